@@ -0,0 +1,142 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ConstDefault;
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+/// Adapter that implements [`Default`] for any [`ConstDefault`] type.
+///
+/// # Note
+///
+/// This is useful to pass types that implement [`ConstDefault`] but not
+/// [`Default`] to generic APIs that only accept `T: Default`.
+/// All other trait implementations forward to the wrapped value.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::{AsDefault, ConstDefault};
+/// struct Counter(u32);
+///
+/// impl ConstDefault for Counter {
+///     const DEFAULT: Self = Counter(0);
+/// }
+///
+/// fn make<T: Default>() -> T {
+///     T::default()
+/// }
+///
+/// let counter = make::<AsDefault<Counter>>();
+/// assert_eq!(counter.into_inner().0, 0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AsDefault<T>(pub T);
+
+impl<T> AsDefault<T> {
+    /// Wraps the given value.
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> ConstDefault for AsDefault<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self(<T as ConstDefault>::DEFAULT);
+}
+
+/// Implemented as `const Default` in the `const_trait` module under the
+/// `unstable` crate feature.
+#[cfg(not(feature = "unstable"))]
+impl<T> Default for AsDefault<T>
+where
+    T: ConstDefault,
+{
+    fn default() -> Self {
+        <Self as ConstDefault>::DEFAULT
+    }
+}
+
+impl<T> From<T> for AsDefault<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for AsDefault<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for AsDefault<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<T> for AsDefault<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for AsDefault<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> Borrow<T> for AsDefault<T> {
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> BorrowMut<T> for AsDefault<T> {
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> fmt::Debug for AsDefault<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T> fmt::Display for AsDefault<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ConstDefault;
+use core::{marker::PhantomData, mem, mem::ManuallyDrop, ptr, slice};
+
+/// Asserts at compilation time that the [`ConstDefault`] value of `T` is all zero bytes.
+///
+/// # Note
+///
+/// This is useful for types of statics that must be placed in zero initialized
+/// memory such as `.bss` instead of being stored as initialized image.
+///
+/// Compilation fails if the default value contains a non-zero byte or a
+/// non-null pointer. Types with padding bytes can not be checked as a whole;
+/// use `#[const_default(assert_zero)]` on structs deriving `ConstDefault`
+/// to check their fields one by one instead.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstDefault;
+/// struct Buffer([u8; 4096]);
+///
+/// impl ConstDefault for Buffer {
+///     const DEFAULT: Self = Buffer([0; 4096]);
+/// }
+///
+/// const _: () = const_default::assert_zero_default::<Buffer>();
+/// static BUFFER: Buffer = Buffer::DEFAULT;
+/// ```
+///
+/// ```compile_fail
+/// # use const_default::ConstDefault;
+/// struct Counter(u32);
+///
+/// impl ConstDefault for Counter {
+///     const DEFAULT: Self = Counter(1);
+/// }
+///
+/// const _: () = const_default::assert_zero_default::<Counter>();
+/// ```
+pub const fn assert_zero_default<T>()
+where
+    T: ConstDefault,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = ZeroDefault::<T>::ASSERT;
+}
+
+/// Inspects the bytes of the default value of `T`.
+///
+/// # Note
+///
+/// The bytes are only ever inspected during constant evaluation which rejects
+/// reads of uninitialized padding bytes and of pointer addresses.
+struct ZeroDefault<T>(PhantomData<T>);
+
+impl<T> ZeroDefault<T>
+where
+    T: ConstDefault,
+{
+    const ASSERT: () = {
+        let value = ManuallyDrop::new(<T as ConstDefault>::DEFAULT);
+        // SAFETY: `value` is valid for reads of `size_of::<T>()` bytes and
+        //         outlives `bytes`. Since this is only evaluated at compilation
+        //         time, reads of padding or pointer bytes are rejected by the
+        //         compiler instead of causing undefined behavior.
+        let bytes = unsafe {
+            slice::from_raw_parts(
+                ptr::addr_of!(value).cast::<u8>(),
+                mem::size_of::<T>(),
+            )
+        };
+        let mut n = 0;
+        while n < bytes.len() {
+            if bytes[n] != 0 {
+                panic!("`ConstDefault::DEFAULT` is not all zero bytes")
+            }
+            n += 1;
+        }
+    };
+}
@@ -0,0 +1,56 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(feature = "alloc")]
+use crate::ConstDefault;
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+
+/// Implements a compilation time default value that borrows its contents.
+///
+/// # Note
+///
+/// The [`ConstDefault`] implementation of [`Cow<'a, T>`]
+/// always produces `Cow::Owned` which requires a `ConstDefault` owned type.
+/// This trait instead produces `Cow::Borrowed` and only requires
+/// `&'a T: ConstDefault` which is also satisfied for types such as `CStr`
+/// whose owned counterpart has no `const` constructor.
+///
+/// Use `#[const_default(borrowed)]` on fields of types deriving `ConstDefault`
+/// to default them via this trait.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # const _: () = {
+/// # use const_default::ConstBorrowed;
+/// use std::{borrow::Cow, ffi::CStr};
+///
+/// const NAME: Cow<CStr> = <Cow<CStr> as ConstBorrowed>::BORROWED;
+/// # };
+/// ```
+pub trait ConstBorrowed {
+    /// The constant borrowed default value.
+    const BORROWED: Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> ConstBorrowed for Cow<'a, T>
+where
+    T: ToOwned + ?Sized + 'a,
+    &'a T: ConstDefault,
+{
+    const BORROWED: Self = Self::Borrowed(<&'a T as ConstDefault>::DEFAULT);
+}
@@ -0,0 +1,259 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    num::Wrapping,
+    sync::atomic::{
+        AtomicBool,
+        AtomicI16,
+        AtomicI32,
+        AtomicI64,
+        AtomicI8,
+        AtomicIsize,
+        AtomicU16,
+        AtomicU32,
+        AtomicU64,
+        AtomicU8,
+        AtomicUsize,
+    },
+    time::Duration,
+};
+
+#[cfg(has_saturating)]
+use core::num::Saturating;
+
+#[cfg(all(feature = "unstable", target_has_atomic = "128"))]
+use core::sync::atomic::{AtomicI128, AtomicU128};
+
+/// Implements a compilation time minimum value for the implemented type.
+///
+/// # Note
+///
+/// This is useful to initialize maximum accumulators in constant evaluation contexts.
+/// Floating point types therefore use negative infinity instead of their finite `MIN`.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstMin;
+/// use core::sync::atomic::{AtomicI32, Ordering};
+///
+/// static HIGHEST: AtomicI32 = <AtomicI32 as ConstMin>::MIN;
+///
+/// HIGHEST.fetch_max(-5, Ordering::Relaxed);
+/// assert_eq!(HIGHEST.load(Ordering::Relaxed), -5);
+/// ```
+pub trait ConstMin {
+    /// The constant minimum value.
+    const MIN: Self;
+}
+
+/// Implements a compilation time maximum value for the implemented type.
+///
+/// # Note
+///
+/// This is useful to initialize minimum accumulators in constant evaluation contexts.
+/// Floating point types therefore use positive infinity instead of their finite `MAX`.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstMax;
+/// use core::sync::atomic::{AtomicU64, Ordering};
+///
+/// static LOWEST: AtomicU64 = <AtomicU64 as ConstMax>::MAX;
+///
+/// LOWEST.fetch_min(42, Ordering::Relaxed);
+/// assert_eq!(LOWEST.load(Ordering::Relaxed), 42);
+/// ```
+pub trait ConstMax {
+    /// The constant maximum value.
+    const MAX: Self;
+}
+
+macro_rules! impl_const_min_max_for_primitive {
+    ( $( $prim:ident ),* ) => {
+        $(
+            impl ConstMin for $prim {
+                const MIN: Self = $prim::MIN;
+            }
+
+            impl ConstMax for $prim {
+                const MAX: Self = $prim::MAX;
+            }
+        )*
+    };
+}
+impl_const_min_max_for_primitive!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! impl_const_min_max_for_float {
+    ( $( $prim:ident ),* ) => {
+        $(
+            impl ConstMin for $prim {
+                const MIN: Self = $prim::NEG_INFINITY;
+            }
+
+            impl ConstMax for $prim {
+                const MAX: Self = $prim::INFINITY;
+            }
+        )*
+    };
+}
+impl_const_min_max_for_float!(f32, f64);
+#[cfg(feature = "unstable")]
+impl_const_min_max_for_float!(f16, f128);
+
+macro_rules! impl_const_min_max_for_atomic_integer {
+    ( $( $atomic_integer:ty: $prim:ident ),* ) => {
+        $(
+            impl ConstMin for $atomic_integer {
+                const MIN: Self = Self::new($prim::MIN);
+            }
+
+            impl ConstMax for $atomic_integer {
+                const MAX: Self = Self::new($prim::MAX);
+            }
+        )*
+    };
+}
+impl_const_min_max_for_atomic_integer!(
+    AtomicI8: i8,
+    AtomicI16: i16,
+    AtomicI32: i32,
+    AtomicI64: i64,
+    AtomicIsize: isize,
+    AtomicU8: u8,
+    AtomicU16: u16,
+    AtomicU32: u32,
+    AtomicU64: u64,
+    AtomicUsize: usize
+);
+#[cfg(all(feature = "unstable", target_has_atomic = "128"))]
+impl_const_min_max_for_atomic_integer!(AtomicI128: i128, AtomicU128: u128);
+
+impl ConstMin for AtomicBool {
+    const MIN: Self = Self::new(false);
+}
+
+impl ConstMax for AtomicBool {
+    const MAX: Self = Self::new(true);
+}
+
+impl ConstMin for bool {
+    const MIN: Self = false;
+}
+
+impl ConstMax for bool {
+    const MAX: Self = true;
+}
+
+impl ConstMin for char {
+    const MIN: Self = '\x00';
+}
+
+impl ConstMax for char {
+    const MAX: Self = char::MAX;
+}
+
+impl ConstMin for Duration {
+    const MIN: Self = Self::ZERO;
+}
+
+impl ConstMax for Duration {
+    const MAX: Self = Self::MAX;
+}
+
+macro_rules! impl_const_min_max_for_wrapper {
+    ( $( $wrapper:ident ),* ) => {
+        $(
+            impl<T> ConstMin for $wrapper<T>
+            where
+                T: ConstMin,
+            {
+                const MIN: Self = Self(<T as ConstMin>::MIN);
+            }
+
+            impl<T> ConstMax for $wrapper<T>
+            where
+                T: ConstMax,
+            {
+                const MAX: Self = Self(<T as ConstMax>::MAX);
+            }
+        )*
+    };
+}
+impl_const_min_max_for_wrapper!(Wrapping);
+#[cfg(has_saturating)]
+impl_const_min_max_for_wrapper!(Saturating);
+
+impl<T, const N: usize> ConstMin for [T; N]
+where
+    T: ConstMin,
+{
+    const MIN: Self = [<T as ConstMin>::MIN; N];
+}
+
+impl<T, const N: usize> ConstMax for [T; N]
+where
+    T: ConstMax,
+{
+    const MAX: Self = [<T as ConstMax>::MAX; N];
+}
+
+macro_rules! impl_const_min_max_for_tuple {
+    ( $( $ty:ident ),* ) => {
+        impl< $($ty),* > ConstMin for ( $($ty ,)* )
+        where
+            $(
+                $ty: ConstMin
+            ),*
+        {
+            const MIN: Self = (
+                $(
+                    <$ty as ConstMin>::MIN,
+                )*
+            );
+        }
+
+        impl< $($ty),* > ConstMax for ( $($ty ,)* )
+        where
+            $(
+                $ty: ConstMax
+            ),*
+        {
+            const MAX: Self = (
+                $(
+                    <$ty as ConstMax>::MAX,
+                )*
+            );
+        }
+    };
+}
+impl_const_min_max_for_tuple!();
+impl_const_min_max_for_tuple!(T1);
+impl_const_min_max_for_tuple!(T1, T2);
+impl_const_min_max_for_tuple!(T1, T2, T3);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4, T5);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4, T5, T6);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4, T5, T6, T7);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_const_min_max_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_const_min_max_for_tuple!(
+    T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12
+);
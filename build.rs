@@ -0,0 +1,83 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Probes the version of the Rust compiler in use.
+//!
+//! Enables `cfg` flags for implementations that depend on constructors
+//! which only became `const` on stable Rust in later compiler versions.
+//!
+//! The probe does not require network access and only invokes `rustc --version`.
+
+use std::{env, process::Command};
+
+/// The `cfg` flags and the minor version of the stable Rust compiler that enables them.
+const CFGS: &[(&str, u32)] = &[
+    // `Mutex::new`, `RwLock::new` and `Condvar::new`
+    ("has_const_mutex_new", 63),
+    // `BTreeMap::new` and `BTreeSet::new`
+    ("has_const_btree_new", 66),
+    // `VecDeque::new`
+    ("has_const_vec_deque_new", 68),
+    // `core::cell::OnceCell` and `std::sync::OnceLock`
+    ("has_once_cell", 70),
+    // `CStr::from_bytes_with_nul`
+    ("has_const_cstr", 72),
+    // `rc::Weak::new` and `sync::Weak::new`
+    ("has_const_weak_new", 73),
+    // `core::num::Saturating`
+    ("has_saturating", 74),
+    // `core::net`
+    ("has_core_net", 77),
+    // `#[diagnostic::on_unimplemented]`
+    ("has_diagnostic_namespace", 78),
+    // `io::empty`, `io::sink` and `io::Cursor::new`
+    ("has_const_io_structs", 79),
+    // `BinaryHeap::new`
+    ("has_const_binary_heap_new", 80),
+    // Mutable references in `const fn`
+    ("has_const_mut_refs", 83),
+    // References to interior mutable data in constants
+    ("has_const_refs_to_cell", 83),
+    // `HashMap::with_hasher`, `HashSet::with_hasher` and `BuildHasherDefault::new`
+    ("has_const_hash_map_with_hasher", 85),
+    // `core::str::from_utf8_mut`
+    ("has_const_from_utf8_mut", 87),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    let minor = rustc_minor_version();
+    for &(cfg, min_minor) in CFGS {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg);
+        if matches!(minor, Some(minor) if minor >= min_minor) {
+            println!("cargo:rustc-cfg={}", cfg);
+        }
+    }
+}
+
+/// Returns the minor version of the Rust compiler in use.
+///
+/// Returns `None` if the version could not be determined in which case
+/// all version dependent implementations are disabled.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    // The output looks like `rustc 1.66.0 (69f9c33d7 2022-12-12)`.
+    let mut parts = version.split_whitespace().nth(1)?.split('.');
+    if parts.next()? != "1" {
+        return None
+    }
+    parts.next()?.parse().ok()
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bridges between [`ConstDefault`] and nightly `const` [`Default`] impls.
+//!
+//! Nightly's `const_trait_impl` allows [`Default`] to be implemented as
+//! `impl const Default`, which makes `Default::default()` callable in
+//! constant evaluation contexts. This module connects both worlds:
+//!
+//! - [`default`] calls `Default::default()` for any `[const] Default` type
+//!   in a `const fn`.
+//! - [`impl_const_default_from_default!`] opts a `const Default` type into
+//!   [`ConstDefault`].
+//! - [`AsDefault<T>`] implements `const Default` for every [`ConstDefault`]
+//!   type `T`.
+//!
+//! [`AsDefault<T>`]: AsDefault
+//! [`impl_const_default_from_default!`]: crate::impl_const_default_from_default
+
+use crate::{AsDefault, ConstDefault};
+
+/// Returns the default value of `T` in constant evaluation contexts.
+///
+/// # Unification with `const Default`
+///
+/// A blanket `impl<T: const Default> ConstDefault for T` would overlap
+/// with the existing implementations of this crate and is therefore not
+/// provided. Instead the plan for unifying both traits is:
+///
+/// 1. While const traits are unstable this function, the
+///    `impl_const_default_from_default!` macro and the `const Default`
+///    impl of [`AsDefault`] live behind the `unstable` crate feature and
+///    the stable API of this crate is unchanged.
+/// 2. Once `impl const Default` is stable the bridge becomes available
+///    without the `unstable` feature, detected by the build script like
+///    other compiler dependent implementations.
+/// 3. [`ConstDefault`] and its associated `DEFAULT` constant stay as they
+///    are, so code written against [`ConstDefault`] keeps working. Types
+///    that implement `const Default` only need a single
+///    [`impl_const_default_from_default!`](crate::impl_const_default_from_default)
+///    invocation to interoperate.
+///
+/// # Example
+///
+/// ```
+/// #![feature(const_trait_impl, const_default)]
+/// # use const_default::{AsDefault, ConstDefault};
+/// struct Counter(u32);
+///
+/// impl ConstDefault for Counter {
+///     const DEFAULT: Self = Counter(42);
+/// }
+///
+/// const VEC: Vec<u8> = const_default::default();
+/// const COUNTER: AsDefault<Counter> = const_default::default();
+/// assert!(VEC.is_empty());
+/// assert_eq!(COUNTER.into_inner().0, 42);
+/// ```
+pub const fn default<T>() -> T
+where
+    T: [const] Default,
+{
+    <T as Default>::default()
+}
+
+/// Implements [`ConstDefault`] for types that implement `const Default`.
+///
+/// The [`ConstDefault::DEFAULT`] of the given types is their
+/// `Default::default()` value.
+///
+/// # Example
+///
+/// ```
+/// #![feature(const_trait_impl, const_default)]
+/// # use const_default::ConstDefault;
+/// #[derive(Debug, PartialEq)]
+/// struct Config {
+///     retries: u32,
+///     verbose: bool,
+/// }
+///
+/// impl const Default for Config {
+///     fn default() -> Self {
+///         Config { retries: 3, verbose: false }
+///     }
+/// }
+///
+/// const_default::impl_const_default_from_default!(Config);
+///
+/// const CONFIG: Config = <Config as ConstDefault>::DEFAULT;
+/// assert_eq!(CONFIG, Config { retries: 3, verbose: false });
+/// ```
+#[macro_export]
+macro_rules! impl_const_default_from_default {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl $crate::ConstDefault for $ty {
+                const DEFAULT: Self = $crate::default::<$ty>();
+            }
+        )*
+    };
+}
+
+impl<T> const Default for AsDefault<T>
+where
+    T: ConstDefault,
+{
+    fn default() -> Self {
+        <Self as ConstDefault>::DEFAULT
+    }
+}
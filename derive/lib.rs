@@ -61,44 +61,651 @@ use syn::{spanned::Spanned, Error};
 ///     Vec3(0.0, 0.0, 0.0),
 /// )
 /// ```
+///
+/// ## Zeroed Struct
+///
+/// Structs marked with `#[const_default(zeroed)]` fail to compile if the
+/// default value of any of their fields is not all zero bytes. The check is
+/// performed when their `DEFAULT` is evaluated and requires Rust 1.83.
+/// Fields of types with padding bytes can not be checked.
+///
+/// ```compile_fail
+/// # use const_default::ConstDefault;
+/// #[derive(ConstDefault)]
+/// #[const_default(zeroed)]
+/// pub struct Config {
+///     name: &'static str,
+/// }
+///
+/// static CONFIG: Config = Config::DEFAULT;
+/// ```
+///
+/// Structs marked with `#[const_default(zeroed(bytemuck))]` or
+/// `#[const_default(zeroed(zerocopy))]` instead default to all-zero bytes via
+/// `ConstZeroed` of the named backend. The types of their fields are checked
+/// to be zeroable with the same backend at compilation time but do not need
+/// to implement `ConstDefault`.
+///
+/// ```
+/// # use const_default::ConstDefault;
+/// #[derive(ConstDefault, Copy, Clone, bytemuck::Zeroable)]
+/// # #[derive(Debug, PartialEq)]
+/// #[const_default(zeroed(bytemuck))]
+/// #[repr(C)]
+/// pub struct Header {
+///     magic: [u8; 4],
+///     length: u32,
+/// }
+///
+/// assert_eq!(
+///     <Header as ConstDefault>::DEFAULT,
+///     Header { magic: [0; 4], length: 0 },
+/// )
+/// ```
+///
+/// ## Zero Assertion
+///
+/// Structs marked with `#[const_default(assert_zero)]` fail to compile if the
+/// default value of any of their fields is not all zero bytes.
+/// This requires Rust 1.83.
+///
+/// ```compile_fail
+/// # use const_default::ConstDefault;
+/// #[derive(ConstDefault)]
+/// #[const_default(assert_zero)]
+/// pub struct Config {
+///     name: &'static str,
+/// }
+/// ```
 #[proc_macro_derive(ConstDefault, attributes(const_default))]
 pub fn derive(input: TokenStream) -> TokenStream {
-    match derive_default(input.into()) {
+    match derive_fieldwise_constant(input.into(), CONST_DEFAULT) {
         Ok(output) => output.into(),
         Err(error) => error.to_compile_error().into(),
     }
 }
 
-/// Implements the derive of `#[derive(ConstDefault)]` for struct types.
-fn derive_default(input: TokenStream2) -> Result<TokenStream2, syn::Error> {
-    let crate_ident = query_crate_ident()?;
+/// Derives an implementation for the [`ConstZero`] trait.
+///
+/// # Note
+///
+/// Only works with newtype `struct` inputs, that is structs with exactly one field.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstZero;
+/// #[derive(ConstZero)]
+/// # #[derive(Debug, PartialEq)]
+/// pub struct Meters(f64);
+///
+/// assert_eq!(<Meters as ConstZero>::ZERO, Meters(0.0))
+/// ```
+#[proc_macro_derive(ConstZero)]
+pub fn derive_zero(input: TokenStream) -> TokenStream {
+    match derive_newtype_constant(input.into(), CONST_ZERO) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Derives an implementation for the [`ConstOne`] trait.
+///
+/// # Note
+///
+/// Only works with newtype `struct` inputs, that is structs with exactly one field.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstOne;
+/// #[derive(ConstOne)]
+/// # #[derive(Debug, PartialEq)]
+/// pub struct Factor {
+///     value: u32,
+/// }
+///
+/// assert_eq!(<Factor as ConstOne>::ONE, Factor { value: 1 })
+/// ```
+#[proc_macro_derive(ConstOne)]
+pub fn derive_one(input: TokenStream) -> TokenStream {
+    match derive_newtype_constant(input.into(), CONST_ONE) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Derives an implementation for the [`ConstMin`] trait.
+///
+/// # Note
+///
+/// The derived minimum is made up of the minimum values of all fields.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstMin;
+/// #[derive(ConstMin)]
+/// # #[derive(Debug, PartialEq)]
+/// pub struct Range {
+///     low: u8,
+///     high: i8,
+/// }
+///
+/// assert_eq!(<Range as ConstMin>::MIN, Range { low: 0, high: -128 })
+/// ```
+#[proc_macro_derive(ConstMin)]
+pub fn derive_min(input: TokenStream) -> TokenStream {
+    match derive_fieldwise_constant(input.into(), CONST_MIN) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Derives an implementation for the [`ConstMax`] trait.
+///
+/// # Note
+///
+/// The derived maximum is made up of the maximum values of all fields.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstMax;
+/// #[derive(ConstMax)]
+/// # #[derive(Debug, PartialEq)]
+/// pub struct Range(u8, i8);
+///
+/// assert_eq!(<Range as ConstMax>::MAX, Range(255, 127))
+/// ```
+#[proc_macro_derive(ConstMax)]
+pub fn derive_max(input: TokenStream) -> TokenStream {
+    match derive_fieldwise_constant(input.into(), CONST_MAX) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Derives an implementation for the [`ConstEmpty`] trait.
+///
+/// # Note
+///
+/// Only works with newtype `struct` inputs, that is structs with exactly one field.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstEmpty;
+/// #[derive(ConstEmpty)]
+/// # #[derive(Debug, PartialEq)]
+/// pub struct Names(Vec<String>);
+///
+/// assert_eq!(<Names as ConstEmpty>::EMPTY, Names(Vec::new()))
+/// ```
+#[proc_macro_derive(ConstEmpty)]
+pub fn derive_empty(input: TokenStream) -> TokenStream {
+    match derive_newtype_constant(input.into(), CONST_EMPTY) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// A derivable trait with a single associated constant.
+#[derive(Copy, Clone, PartialEq)]
+struct ConstTrait {
+    /// The name of the trait, e.g. `ConstDefault`.
+    name: &'static str,
+    /// The name of the associated constant of the trait, e.g. `DEFAULT`.
+    constant: &'static str,
+}
+
+const CONST_DEFAULT: ConstTrait = ConstTrait {
+    name: "ConstDefault",
+    constant: "DEFAULT",
+};
+const CONST_ZERO: ConstTrait = ConstTrait {
+    name: "ConstZero",
+    constant: "ZERO",
+};
+const CONST_ONE: ConstTrait = ConstTrait {
+    name: "ConstOne",
+    constant: "ONE",
+};
+const CONST_MIN: ConstTrait = ConstTrait {
+    name: "ConstMin",
+    constant: "MIN",
+};
+const CONST_MAX: ConstTrait = ConstTrait {
+    name: "ConstMax",
+    constant: "MAX",
+};
+const CONST_EMPTY: ConstTrait = ConstTrait {
+    name: "ConstEmpty",
+    constant: "EMPTY",
+};
+const CONST_UNINIT: ConstTrait = ConstTrait {
+    name: "ConstUninit",
+    constant: "UNINIT",
+};
+const CONST_BORROWED: ConstTrait = ConstTrait {
+    name: "ConstBorrowed",
+    constant: "BORROWED",
+};
+
+impl ConstTrait {
+    /// Returns the identifier of the trait.
+    fn trait_ident(self) -> Ident {
+        Ident::new(self.name, Span::call_site())
+    }
+
+    /// Returns the identifier of the associated constant of the trait.
+    fn const_ident(self) -> Ident {
+        Ident::new(self.constant, Span::call_site())
+    }
+}
+
+/// Implements the derive of `const_trait` for struct types.
+///
+/// The generated constant is made up of the constants of all fields.
+fn derive_fieldwise_constant(
+    input: TokenStream2,
+    const_trait: ConstTrait,
+) -> Result<TokenStream2, syn::Error> {
+    let input = syn::parse2::<syn::DeriveInput>(input)?;
+    let data_struct = expect_struct(input.data, const_trait)?;
+    let options = struct_options(&input.attrs, const_trait)?;
+    let zero_assertion = if options.assert_zero {
+        generate_zero_assertion(&input.generics, &data_struct)?
+    } else {
+        TokenStream2::new()
+    };
+    let const_impl = match options.zeroed {
+        Some(backend) => {
+            generate_zeroed_impl(
+                input.ident,
+                input.generics,
+                &data_struct,
+                backend,
+            )?
+        }
+        None => {
+            generate_impl(
+                input.ident,
+                input.generics,
+                &data_struct,
+                const_trait,
+            )?
+        }
+    };
+    Ok(quote! {
+        #const_impl
+        #zero_assertion
+    })
+}
+
+/// Implements the derive of `const_trait` for newtype struct types.
+///
+/// The generated constant forwards to the constant of the only field.
+fn derive_newtype_constant(
+    input: TokenStream2,
+    const_trait: ConstTrait,
+) -> Result<TokenStream2, syn::Error> {
     let input = syn::parse2::<syn::DeriveInput>(input)?;
-    let ident = input.ident;
-    let data_struct = match input.data {
-        syn::Data::Struct(data_struct) => data_struct,
+    let data_struct = expect_struct(input.data, const_trait)?;
+    if data_struct.fields.len() != 1 {
+        return Err(Error::new(
+            data_struct.fields.span(),
+            format!(
+                "{} derive only works on structs with exactly one field",
+                const_trait.name
+            ),
+        ))
+    }
+    generate_impl(input.ident, input.generics, &data_struct, const_trait)
+}
+
+/// Returns the `struct` data of the input or an error for the derive of `const_trait`.
+fn expect_struct(
+    data: syn::Data,
+    const_trait: ConstTrait,
+) -> Result<syn::DataStruct, syn::Error> {
+    match data {
+        syn::Data::Struct(data_struct) => Ok(data_struct),
         _ => {
-            return Err(Error::new(
+            Err(Error::new(
                 Span::call_site(),
-                "ConstDefault derive only works on struct types",
+                format!(
+                    "{} derive only works on struct types",
+                    const_trait.name
+                ),
             ))
         }
-    };
-    let default_impl =
-        generate_default_impl_struct(&crate_ident, &data_struct)?;
-    let mut generics = input.generics;
-    generate_default_impl_where_bounds(
+    }
+}
+
+/// Generates the `const_trait` implementation for the `struct` input type.
+fn generate_impl(
+    ident: Ident,
+    mut generics: syn::Generics,
+    data_struct: &syn::DataStruct,
+    const_trait: ConstTrait,
+) -> Result<TokenStream2, syn::Error> {
+    let crate_ident = query_crate_ident()?;
+    let trait_ident = const_trait.trait_ident();
+    let const_ident = const_trait.const_ident();
+    let field_traits = data_struct
+        .fields
+        .iter()
+        .map(|field| field_const_trait(field, const_trait))
+        .collect::<Result<Vec<_>, _>>()?;
+    let const_impl =
+        generate_impl_struct(&crate_ident, data_struct, &field_traits)?;
+    generate_impl_where_bounds(
         &crate_ident,
-        &data_struct,
+        data_struct,
         &mut generics,
+        &field_traits,
     )?;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #crate_ident::#trait_ident for #ident #ty_generics #where_clause {
+            const #const_ident: Self = #const_impl;
+        }
+    })
+}
+
+/// Returns the trait providing the constant of the field for the derive of `const_trait`.
+///
+/// # Note
+///
+/// Fields of `ConstDefault` derives may be marked with `#[const_default(uninit)]`
+/// or `#[const_default(borrowed)]` in which case their constant is provided
+/// by `ConstUninit` or `ConstBorrowed` respectively.
+fn field_const_trait(
+    field: &syn::Field,
+    const_trait: ConstTrait,
+) -> Result<ConstTrait, syn::Error> {
+    let mut field_trait = const_trait;
+    if const_trait != CONST_DEFAULT {
+        return Ok(field_trait)
+    }
+    for attr in &field.attrs {
+        if !attr.path.is_ident("const_default") {
+            continue
+        }
+        let nested = match attr.parse_meta()? {
+            syn::Meta::List(list) => list.nested,
+            meta => {
+                return Err(Error::new(
+                    meta.span(),
+                    "expected a list of options: `#[const_default(..)]`",
+                ))
+            }
+        };
+        for option in &nested {
+            match option {
+                syn::NestedMeta::Meta(syn::Meta::Path(path))
+                    if path.is_ident("uninit") =>
+                {
+                    field_trait = CONST_UNINIT
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(path))
+                    if path.is_ident("borrowed") =>
+                {
+                    field_trait = CONST_BORROWED
+                }
+                _ => return Err(Error::new(
+                    option.span(),
+                    "unknown `const_default` field option, expected `uninit` or `borrowed`",
+                )),
+            }
+        }
+    }
+    Ok(field_trait)
+}
+
+/// How a `#[const_default(zeroed)]` struct is defaulted to all-zero bytes.
+enum Zeroed {
+    /// The defaults of all fields are asserted to be all zero bytes.
+    Fieldwise,
+    /// The struct is zeroed by the given `ConstZeroed` backend, e.g. `ViaBytemuck`.
+    Backend(Ident),
+}
+
+/// The options of a struct given by `#[const_default(..)]` attributes.
+#[derive(Default)]
+struct StructOptions {
+    /// Whether and how the struct is marked with `#[const_default(zeroed)]`.
+    zeroed: Option<Zeroed>,
+    /// Whether the struct is marked with `#[const_default(assert_zero)]`.
+    assert_zero: bool,
+}
+
+/// Returns the options given by the `#[const_default(..)]` attributes of the struct.
+///
+/// # Note
+///
+/// Only `ConstDefault` derives support struct options.
+fn struct_options(
+    attrs: &[syn::Attribute],
+    const_trait: ConstTrait,
+) -> Result<StructOptions, syn::Error> {
+    let mut options = StructOptions::default();
+    if const_trait != CONST_DEFAULT {
+        return Ok(options)
+    }
+    for attr in attrs {
+        if !attr.path.is_ident("const_default") {
+            continue
+        }
+        let nested = match attr.parse_meta()? {
+            syn::Meta::List(list) => list.nested,
+            meta => {
+                return Err(Error::new(
+                    meta.span(),
+                    "expected a list of options: `#[const_default(..)]`",
+                ))
+            }
+        };
+        for option in &nested {
+            match option {
+                syn::NestedMeta::Meta(syn::Meta::Path(path))
+                    if path.is_ident("zeroed") =>
+                {
+                    options.zeroed = Some(Zeroed::Fieldwise)
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(path))
+                    if path.is_ident("assert_zero") =>
+                {
+                    options.assert_zero = true
+                }
+                syn::NestedMeta::Meta(syn::Meta::List(list))
+                    if list.path.is_ident("zeroed") =>
+                {
+                    if list.nested.len() != 1 {
+                        return Err(Error::new(
+                            list.span(),
+                            "expected a single `zeroed` backend: `zeroed(bytemuck)` or `zeroed(zerocopy)`",
+                        ))
+                    }
+                    let name = match &list.nested[0] {
+                        syn::NestedMeta::Meta(syn::Meta::Path(path))
+                            if path.is_ident("bytemuck") =>
+                        {
+                            "ViaBytemuck"
+                        }
+                        syn::NestedMeta::Meta(syn::Meta::Path(path))
+                            if path.is_ident("zerocopy") =>
+                        {
+                            "ViaZerocopy"
+                        }
+                        unknown => return Err(Error::new(
+                            unknown.span(),
+                            "unknown `zeroed` backend, expected `bytemuck` or `zerocopy`",
+                        )),
+                    };
+                    options.zeroed = Some(Zeroed::Backend(Ident::new(
+                        name,
+                        Span::call_site(),
+                    )))
+                }
+                _ => return Err(Error::new(
+                    option.span(),
+                    "unknown `const_default` struct option, expected `zeroed` or `assert_zero`",
+                )),
+            }
+        }
+    }
+    Ok(options)
+}
+
+/// Generates the `ConstDefault` implementation of a `#[const_default(zeroed)]` struct.
+///
+/// # Note
+///
+/// Without a backend the generated constant is made up of the `ConstDefault`
+/// values of all fields which are asserted to be all zero bytes.
+/// With a backend the generated constant is the `ConstZeroed` value of the
+/// struct and every field is checked to be zeroable with the same backend.
+fn generate_zeroed_impl(
+    ident: Ident,
+    mut generics: syn::Generics,
+    data_struct: &syn::DataStruct,
+    zeroed: Zeroed,
+) -> Result<TokenStream2, syn::Error> {
+    let crate_ident = query_crate_ident()?;
+    for field in &data_struct.fields {
+        if field_const_trait(field, CONST_DEFAULT)? != CONST_DEFAULT {
+            return Err(Error::new(
+                field.span(),
+                "field options are not supported on `#[const_default(zeroed)]` structs",
+            ))
+        }
+    }
+    let backend = match zeroed {
+        Zeroed::Fieldwise => {
+            return generate_fieldwise_zeroed_impl(
+                &crate_ident,
+                ident,
+                generics,
+                data_struct,
+            )
+        }
+        Zeroed::Backend(backend) => backend,
+    };
+    let backend = quote! { #crate_ident::#backend };
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let self_ty: syn::Type = syn::parse_quote!(#ident #ty_generics);
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#self_ty: #crate_ident::ConstZeroed<#backend>));
+    let field_checks = data_struct.fields.iter().map(|field| {
+        let field_span = field.span();
+        let field_type = &field.ty;
+        quote_spanned!(field_span=>
+            let _ = ::core::mem::ManuallyDrop::new(
+                <#field_type as #crate_ident::ConstZeroed<#backend>>::ZEROED
+            );
+        )
+    });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #crate_ident::ConstDefault for #ident #ty_generics #where_clause {
+            const DEFAULT: Self = {
+                #( #field_checks )*
+                <Self as #crate_ident::ConstZeroed<#backend>>::ZEROED
+            };
+        }
+    })
+}
+
+/// Generates the `ConstDefault` implementation of a `#[const_default(zeroed)]`
+/// struct without a backend.
+///
+/// # Note
+///
+/// The `ConstDefault` value of every field is asserted to be all zero bytes
+/// when the generated constant is evaluated which also works for generic structs.
+fn generate_fieldwise_zeroed_impl(
+    crate_ident: &TokenStream2,
+    ident: Ident,
+    mut generics: syn::Generics,
+    data_struct: &syn::DataStruct,
+) -> Result<TokenStream2, syn::Error> {
+    let field_traits = vec![CONST_DEFAULT; data_struct.fields.len()];
+    let const_impl =
+        generate_impl_struct(crate_ident, data_struct, &field_traits)?;
+    generate_impl_where_bounds(
+        crate_ident,
+        data_struct,
+        &mut generics,
+        &field_traits,
+    )?;
+    let field_checks = data_struct.fields.iter().map(|field| {
+        let field_span = field.span();
+        let field_type = &field.ty;
+        quote_spanned!(field_span=>
+            #crate_ident::assert_zero_default::<#field_type>();
+        )
+    });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     Ok(quote! {
         impl #impl_generics #crate_ident::ConstDefault for #ident #ty_generics #where_clause {
-            const DEFAULT: Self = #default_impl;
+            const DEFAULT: Self = {
+                #( #field_checks )*
+                #const_impl
+            };
         }
     })
 }
 
+/// Generates the compilation time check of a `#[const_default(assert_zero)]` struct.
+///
+/// # Note
+///
+/// The `ConstDefault` value of every field is asserted to be all zero bytes.
+/// Checking fields one by one skips the padding bytes of the struct.
+/// Fields marked with `#[const_default(uninit)]` are not checked.
+fn generate_zero_assertion(
+    generics: &syn::Generics,
+    data_struct: &syn::DataStruct,
+) -> Result<TokenStream2, syn::Error> {
+    if !generics.params.is_empty() {
+        return Err(Error::new(
+            generics.span(),
+            "`#[const_default(assert_zero)]` does not support generic structs",
+        ))
+    }
+    let crate_ident = query_crate_ident()?;
+    let mut field_checks = Vec::new();
+    for field in &data_struct.fields {
+        let field_trait = field_const_trait(field, CONST_DEFAULT)?;
+        if field_trait == CONST_UNINIT {
+            continue
+        }
+        if field_trait != CONST_DEFAULT {
+            return Err(Error::new(
+                field.span(),
+                format!(
+                    "`{}` fields are never all zero bytes",
+                    field_trait.name
+                ),
+            ))
+        }
+        let field_span = field.span();
+        let field_type = &field.ty;
+        field_checks.push(quote_spanned!(field_span=>
+            #crate_ident::assert_zero_default::<#field_type>();
+        ));
+    }
+    Ok(quote! {
+        const _: () = {
+            #( #field_checks )*
+        };
+    })
+}
+
 /// Queries the dependencies for the derive root crate name and returns the identifier.
 ///
 /// # Note
@@ -123,7 +730,7 @@ fn query_crate_ident() -> Result<TokenStream2, syn::Error> {
     }
 }
 
-/// Generates the `ConstDefault` implementation for `struct` input types.
+/// Generates the `const_trait` constant for `struct` input types.
 ///
 /// # Note
 ///
@@ -132,12 +739,15 @@ fn query_crate_ident() -> Result<TokenStream2, syn::Error> {
 /// raw number literals in case of tuple-structs.
 ///
 /// For example `struct Foo(u32)` can be represented as `Foo { 0: 42 }`.
-fn generate_default_impl_struct(
+fn generate_impl_struct(
     crate_ident: &TokenStream2,
     data_struct: &syn::DataStruct,
+    field_traits: &[ConstTrait],
 ) -> Result<TokenStream2, syn::Error> {
-    let fields_impl =
-        data_struct.fields.iter().enumerate().map(|(n, field)| {
+    let fields_impl = data_struct.fields.iter().zip(field_traits).enumerate().map(
+        |(n, (field, field_trait))| {
+            let trait_ident = field_trait.trait_ident();
+            let const_ident = field_trait.const_ident();
             let field_span = field.span();
             let field_type = &field.ty;
             let field_pos = Literal::usize_unsuffixed(n);
@@ -147,9 +757,10 @@ fn generate_default_impl_struct(
                 .map(|ident| quote_spanned!(field_span=> #ident))
                 .unwrap_or_else(|| quote_spanned!(field_span=> #field_pos));
             quote_spanned!(field_span=>
-                #field_ident: <#field_type as #crate_ident::ConstDefault>::DEFAULT
+                #field_ident: <#field_type as #crate_ident::#trait_ident>::#const_ident
             )
-        });
+        },
+    );
     Ok(quote! {
         Self {
             #( #fields_impl ),*
@@ -157,17 +768,19 @@ fn generate_default_impl_struct(
     })
 }
 
-/// Generates `ConstDefault` where bounds for all fields of the input.
-fn generate_default_impl_where_bounds(
+/// Generates where bounds on the field traits for all fields of the input.
+fn generate_impl_where_bounds(
     crate_ident: &TokenStream2,
     data_struct: &syn::DataStruct,
     generics: &mut syn::Generics,
+    field_traits: &[ConstTrait],
 ) -> Result<(), syn::Error> {
     let where_clause = generics.make_where_clause();
-    for field in &data_struct.fields {
+    for (field, field_trait) in data_struct.fields.iter().zip(field_traits) {
+        let trait_ident = field_trait.trait_ident();
         let field_type = &field.ty;
         where_clause.predicates.push(syn::parse_quote!(
-            #field_type: #crate_ident::ConstDefault
+            #field_type: #crate_ident::#trait_ident
         ))
     }
     Ok(())
@@ -0,0 +1,186 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ConstDefault;
+use core::iter::{self, Empty};
+
+#[cfg(has_const_cstr)]
+use core::ffi::CStr;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::LinkedList, string::String, vec::Vec};
+
+#[cfg(all(feature = "alloc", feature = "unstable"))]
+use alloc::alloc::Allocator;
+
+#[cfg(all(feature = "alloc", has_const_btree_new))]
+use alloc::collections::{BTreeMap, BTreeSet};
+
+#[cfg(all(feature = "alloc", has_const_vec_deque_new))]
+use alloc::collections::VecDeque;
+
+#[cfg(all(feature = "alloc", has_const_binary_heap_new))]
+use alloc::collections::BinaryHeap;
+
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+use std::collections::{HashMap, HashSet};
+
+/// Implements a compilation time empty value for the implemented container type.
+///
+/// # Note
+///
+/// Unlike [`ConstDefault`] this trait is only implemented
+/// by containers and its `EMPTY` value is guaranteed to contain no elements.
+/// For example `[u8; N]` implements `ConstDefault` but not `ConstEmpty`
+/// since its default value contains `N` zeroed elements.
+///
+/// The guarantee can be checked in constant evaluation contexts where the
+/// container provides a `const fn is_empty`.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstEmpty;
+/// const EMPTY: &[u8] = <&[u8] as ConstEmpty>::EMPTY;
+/// const _: () = assert!(EMPTY.is_empty());
+/// ```
+pub trait ConstEmpty {
+    /// The constant empty value.
+    const EMPTY: Self;
+}
+
+impl<'a, T> ConstEmpty for &'a [T]
+where
+    T: 'a,
+{
+    const EMPTY: Self = &[];
+}
+
+#[cfg(has_const_mut_refs)]
+impl<'a, T> ConstEmpty for &'a mut [T]
+where
+    T: 'a,
+{
+    const EMPTY: Self = <Self as ConstDefault>::DEFAULT;
+}
+
+impl ConstEmpty for &str {
+    const EMPTY: Self = "";
+}
+
+#[cfg(all(has_const_mut_refs, has_const_from_utf8_mut))]
+impl ConstEmpty for &mut str {
+    const EMPTY: Self = <Self as ConstDefault>::DEFAULT;
+}
+
+#[cfg(has_const_cstr)]
+impl ConstEmpty for &CStr {
+    const EMPTY: Self = <Self as ConstDefault>::DEFAULT;
+}
+
+impl<T> ConstEmpty for Empty<T> {
+    const EMPTY: Self = iter::empty();
+}
+
+#[cfg(feature = "alloc")]
+impl ConstEmpty for String {
+    const EMPTY: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", not(feature = "unstable")))]
+impl<T> ConstEmpty for Vec<T> {
+    const EMPTY: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", feature = "unstable"))]
+impl<T, A> ConstEmpty for Vec<T, A>
+where
+    A: Allocator + ConstDefault,
+{
+    const EMPTY: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "alloc", not(feature = "unstable")))]
+impl<T> ConstEmpty for LinkedList<T> {
+    const EMPTY: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", feature = "unstable"))]
+impl<T, A> ConstEmpty for LinkedList<T, A>
+where
+    A: Allocator + ConstDefault,
+{
+    const EMPTY: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "alloc", has_const_btree_new))]
+impl<K: Ord, V> ConstEmpty for BTreeMap<K, V> {
+    const EMPTY: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", has_const_btree_new))]
+impl<T: Ord> ConstEmpty for BTreeSet<T> {
+    const EMPTY: Self = Self::new();
+}
+
+#[cfg(all(
+    feature = "alloc",
+    has_const_vec_deque_new,
+    not(feature = "unstable")
+))]
+impl<T> ConstEmpty for VecDeque<T> {
+    const EMPTY: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", has_const_vec_deque_new, feature = "unstable"))]
+impl<T, A> ConstEmpty for VecDeque<T, A>
+where
+    A: Allocator + ConstDefault,
+{
+    const EMPTY: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(
+    feature = "alloc",
+    has_const_binary_heap_new,
+    not(feature = "unstable")
+))]
+impl<T: Ord> ConstEmpty for BinaryHeap<T> {
+    const EMPTY: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", has_const_binary_heap_new, feature = "unstable"))]
+impl<T, A> ConstEmpty for BinaryHeap<T, A>
+where
+    T: Ord,
+    A: Allocator + ConstDefault,
+{
+    const EMPTY: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+impl<K, V, S> ConstEmpty for HashMap<K, V, S>
+where
+    S: ConstDefault,
+{
+    const EMPTY: Self = Self::with_hasher(<S as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+impl<T, S> ConstEmpty for HashSet<T, S>
+where
+    S: ConstDefault,
+{
+    const EMPTY: Self = Self::with_hasher(<S as ConstDefault>::DEFAULT);
+}
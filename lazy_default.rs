@@ -0,0 +1,162 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ConstDefault;
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(not(feature = "std"))]
+use core::cell::OnceCell as Cell;
+#[cfg(feature = "std")]
+use std::sync::OnceLock as Cell;
+
+/// Lazily initialized slot for types that only implement [`Default`].
+///
+/// The wrapped value is constructed via [`Default::default`] upon first access.
+///
+/// # Note
+///
+/// This allows to use types without `const` constructors, such as `HashMap`
+/// or `PathBuf`, in `static` items and in types deriving [`ConstDefault`].
+///
+/// With the `std` crate feature enabled this is backed by
+/// [`OnceLock`](std::sync::OnceLock) and thus is thread-safe.
+/// Otherwise it is backed by the unsynchronized [`OnceCell`](core::cell::OnceCell).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// # use const_default::LazyDefault;
+/// use std::collections::HashMap;
+///
+/// static NAMES: LazyDefault<HashMap<u32, String>> = LazyDefault::new();
+///
+/// assert!(NAMES.is_empty());
+/// # }
+/// ```
+pub struct LazyDefault<T> {
+    cell: Cell<T>,
+}
+
+impl<T> LazyDefault<T> {
+    /// Creates a new uninitialized slot.
+    pub const fn new() -> Self {
+        Self { cell: Cell::new() }
+    }
+
+    /// Returns the wrapped value if it has already been initialized.
+    ///
+    /// # Note
+    ///
+    /// This is an associated function so that it does not shadow methods
+    /// of the wrapped type, use it as `LazyDefault::get(&slot)`.
+    pub fn get(this: &Self) -> Option<&T> {
+        this.cell.get()
+    }
+}
+
+impl<T> LazyDefault<T>
+where
+    T: Default,
+{
+    /// Returns a shared reference to the wrapped value.
+    ///
+    /// Initializes the wrapped value if this is the first access.
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(T::default)
+    }
+
+    /// Returns an exclusive reference to the wrapped value.
+    ///
+    /// Initializes the wrapped value if this is the first access.
+    pub fn force_mut(this: &mut Self) -> &mut T {
+        Self::force(this);
+        match this.cell.get_mut() {
+            Some(value) => value,
+            None => unreachable!("value has been initialized above"),
+        }
+    }
+
+    /// Returns the wrapped value.
+    ///
+    /// Initializes the wrapped value if it has never been accessed.
+    pub fn into_inner(this: Self) -> T {
+        this.cell.into_inner().unwrap_or_default()
+    }
+}
+
+impl<T> ConstDefault for LazyDefault<T> {
+    const DEFAULT: Self = Self::new();
+}
+
+impl<T> Default for LazyDefault<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<T> for LazyDefault<T> {
+    fn from(value: T) -> Self {
+        Self {
+            cell: Cell::from(value),
+        }
+    }
+}
+
+impl<T> Clone for LazyDefault<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<T> Deref for LazyDefault<T>
+where
+    T: Default,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        Self::force(self)
+    }
+}
+
+impl<T> DerefMut for LazyDefault<T>
+where
+    T: Default,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        Self::force_mut(self)
+    }
+}
+
+impl<T> fmt::Debug for LazyDefault<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match Self::get(self) {
+            Some(value) => f.debug_tuple("LazyDefault").field(value).finish(),
+            None => f.write_str("LazyDefault(<uninit>)"),
+        }
+    }
+}
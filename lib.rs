@@ -14,8 +14,21 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(
-    all(feature = "alloc", feature = "unstable"),
-    feature(const_btree_new)
+    feature = "unstable",
+    feature(
+        allocator_api,
+        const_default,
+        const_trait_impl,
+        exclusive_wrapper,
+        sync_unsafe_cell,
+        f16,
+        f128,
+        portable_simd
+    )
+)]
+#![cfg_attr(
+    all(feature = "unstable", target_has_atomic = "128"),
+    feature(integer_atomics)
 )]
 #![allow(clippy::declare_interior_mutable_const)]
 
@@ -23,14 +36,70 @@
 extern crate alloc;
 
 #[cfg(feature = "derive")]
-pub use const_default_derive::ConstDefault;
+pub use const_default_derive::{
+    ConstDefault,
+    ConstEmpty,
+    ConstMax,
+    ConstMin,
+    ConstOne,
+    ConstZero,
+};
+
+mod as_default;
+#[cfg(has_const_refs_to_cell)]
+mod assert_zero;
+mod borrowed;
+mod bounds;
+#[cfg(feature = "unstable")]
+mod const_trait;
+mod empty;
+#[cfg(has_once_cell)]
+mod lazy_default;
+mod num;
+mod static_default;
+mod uninit;
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+mod zeroed;
+
+pub use self::{
+    as_default::AsDefault,
+    borrowed::ConstBorrowed,
+    bounds::{ConstMax, ConstMin},
+    empty::ConstEmpty,
+    num::{ConstOne, ConstZero},
+    uninit::ConstUninit,
+};
+
+#[doc(hidden)]
+pub use self::static_default::assert_static_sync as __assert_static_sync;
+
+#[cfg(has_once_cell)]
+pub use self::lazy_default::LazyDefault;
+
+#[cfg(has_const_refs_to_cell)]
+pub use self::assert_zero::assert_zero_default;
+
+#[cfg(feature = "unstable")]
+pub use self::const_trait::default;
+
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+pub use self::zeroed::ConstZeroed;
+
+#[cfg(feature = "bytemuck")]
+pub use self::zeroed::ViaBytemuck;
+
+#[cfg(feature = "zerocopy")]
+pub use self::zeroed::ViaZerocopy;
 
 use core::{
     cell::{Cell, RefCell, UnsafeCell},
+    cmp::Reverse,
+    fmt,
     iter::{self, Empty},
     marker::{PhantomData, PhantomPinned},
     mem::{ManuallyDrop, MaybeUninit},
     num::Wrapping,
+    ops::{Range, RangeFrom, RangeFull, RangeTo},
     ptr,
     sync::atomic::{
         AtomicBool,
@@ -49,6 +118,41 @@ use core::{
     time::Duration,
 };
 
+#[cfg(has_const_hash_map_with_hasher)]
+use core::hash::BuildHasherDefault;
+
+#[cfg(has_once_cell)]
+use core::cell::OnceCell;
+
+#[cfg(has_const_cstr)]
+use core::ffi::CStr;
+
+#[cfg(has_saturating)]
+use core::num::Saturating;
+
+#[cfg(feature = "unstable")]
+use core::{
+    cell::SyncUnsafeCell,
+    simd::{Simd, SimdElement},
+    sync::SyncView,
+};
+
+#[cfg(all(feature = "unstable", target_has_atomic = "128"))]
+use core::sync::atomic::{AtomicI128, AtomicU128};
+
+#[cfg(feature = "neutral-values")]
+use core::ops::Bound;
+
+#[cfg(all(feature = "neutral-values", has_core_net))]
+use core::net::{
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+    SocketAddr,
+    SocketAddrV4,
+    SocketAddrV6,
+};
+
 #[cfg(feature = "alloc")]
 use alloc::{
     borrow::Cow,
@@ -59,8 +163,45 @@ use alloc::{
 };
 
 #[cfg(all(feature = "alloc", feature = "unstable"))]
+use alloc::alloc::{Allocator, Global};
+
+#[cfg(all(feature = "alloc", has_const_btree_new))]
 use alloc::collections::{BTreeMap, BTreeSet};
 
+#[cfg(all(feature = "alloc", has_const_vec_deque_new))]
+use alloc::collections::VecDeque;
+
+#[cfg(all(feature = "alloc", has_const_binary_heap_new))]
+use alloc::collections::BinaryHeap;
+
+#[cfg(all(feature = "alloc", has_const_weak_new))]
+use alloc::rc;
+
+#[cfg(all(
+    feature = "alloc",
+    has_const_weak_new,
+    target_has_atomic = "ptr"
+))]
+use alloc::sync;
+
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "std")]
+use std::{alloc::System, sync::Once};
+
+#[cfg(all(feature = "std", has_const_io_structs))]
+use std::io;
+
+#[cfg(all(feature = "std", feature = "neutral-values"))]
+use std::time::SystemTime;
+
+#[cfg(all(feature = "std", has_const_mutex_new))]
+use std::sync::{Condvar, Mutex, RwLock};
+
+#[cfg(all(feature = "std", has_once_cell))]
+use std::sync::OnceLock;
+
 /// Implements a compilation time default value for the implemented type.
 ///
 /// # Note
@@ -83,6 +224,28 @@ use alloc::collections::{BTreeMap, BTreeSet};
 /// ```compile_fail
 /// const VEC: Vec<u8> = <Vec<u8> as Default>::default();
 /// ```
+///
+/// # Hash Maps
+///
+/// Since Rust 1.85 `HashMap` and `HashSet` implement this trait for hashers
+/// that implement `ConstDefault` themselves, such as `BuildHasherDefault<H>`.
+///
+/// The default `RandomState` hasher is seeded at runtime and thus has no
+/// compilation time default value:
+///
+/// ```compile_fail
+/// # use const_default::ConstDefault;
+/// use std::collections::HashMap;
+///
+/// const MAP: HashMap<u32, u32> = <HashMap<u32, u32> as ConstDefault>::DEFAULT;
+/// ```
+#[cfg_attr(
+    has_diagnostic_namespace,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has no compilation time default value",
+        label = "missing `ConstDefault` implementation"
+    )
+)]
 pub trait ConstDefault {
     /// The constant default value.
     const DEFAULT: Self;
@@ -122,6 +285,8 @@ impl_const_default_for_atomic_integer!(
     AtomicU64,
     AtomicUsize
 );
+#[cfg(all(feature = "unstable", target_has_atomic = "128"))]
+impl_const_default_for_atomic_integer!(AtomicI128, AtomicU128);
 
 impl ConstDefault for AtomicBool {
     const DEFAULT: Self = Self::new(false);
@@ -141,6 +306,8 @@ macro_rules! impl_const_default_for_float {
     };
 }
 impl_const_default_for_float!(f32, f64);
+#[cfg(feature = "unstable")]
+impl_const_default_for_float!(f16, f128);
 
 impl<T, const N: usize> ConstDefault for [T; N]
 where
@@ -156,6 +323,25 @@ where
     const DEFAULT: Self = &[];
 }
 
+/// Returns an empty mutable slice.
+///
+/// # Note
+///
+/// Mutable borrows of temporaries are not allowed in associated constants
+/// while empty arrays are promoted in `const fn` bodies.
+#[cfg(has_const_mut_refs)]
+const fn empty_slice_mut<'a, T>() -> &'a mut [T] {
+    &mut []
+}
+
+#[cfg(has_const_mut_refs)]
+impl<'a, T> ConstDefault for &'a mut [T]
+where
+    T: 'a,
+{
+    const DEFAULT: Self = empty_slice_mut();
+}
+
 macro_rules! impl_const_default_for_tuple {
     ( $( $ty:ident ),* ) => {
         impl< $($ty),* > ConstDefault for ( $($ty ,)* )
@@ -188,6 +374,51 @@ impl_const_default_for_tuple!(
     T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12
 );
 
+macro_rules! impl_const_default_for_fn {
+    ( $( $arg:ident ),* ) => {
+        impl_const_default_for_fn!(@impl []; $($arg),*);
+        impl_const_default_for_fn!(@impl [unsafe]; $($arg),*);
+        impl_const_default_for_fn!(@impl [extern "C"]; $($arg),*);
+        impl_const_default_for_fn!(@impl [unsafe extern "C"]; $($arg),*);
+    };
+    ( @impl [ $($qualifier:tt)* ]; $( $arg:ident ),* ) => {
+        /// Defaults to a no-op function that ignores its arguments and returns `R::DEFAULT`.
+        ///
+        /// # Note
+        ///
+        /// Function pointers that are generic over lifetimes, such as `fn(&T)`,
+        /// are not covered by this implementation.
+        impl<R, $($arg),*> ConstDefault for $($qualifier)* fn( $($arg),* ) -> R
+        where
+            R: ConstDefault,
+        {
+            const DEFAULT: Self = {
+                #[allow(clippy::too_many_arguments)]
+                $($qualifier)* fn noop<R, $($arg),*>( $( _: $arg ),* ) -> R
+                where
+                    R: ConstDefault,
+                {
+                    <R as ConstDefault>::DEFAULT
+                }
+                noop::<R, $($arg),*>
+            };
+        }
+    };
+}
+impl_const_default_for_fn!();
+impl_const_default_for_fn!(T1);
+impl_const_default_for_fn!(T1, T2);
+impl_const_default_for_fn!(T1, T2, T3);
+impl_const_default_for_fn!(T1, T2, T3, T4);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5, T6);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5, T6, T7);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5, T6, T7, T8);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_const_default_for_fn!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
 impl ConstDefault for bool {
     const DEFAULT: Self = false;
 }
@@ -200,25 +431,62 @@ impl ConstDefault for &str {
     const DEFAULT: Self = "";
 }
 
+#[cfg(all(has_const_mut_refs, has_const_from_utf8_mut))]
+impl ConstDefault for &mut str {
+    const DEFAULT: Self = match core::str::from_utf8_mut(empty_slice_mut()) {
+        Ok(empty) => empty,
+        Err(_) => panic!("the empty string is valid UTF-8"),
+    };
+}
+
+#[cfg(has_const_cstr)]
+impl ConstDefault for &CStr {
+    const DEFAULT: Self = match CStr::from_bytes_with_nul(b"\0") {
+        Ok(empty) => empty,
+        Err(_) => panic!("the empty C string is nul terminated"),
+    };
+}
+
 impl<T> ConstDefault for Option<T> {
     const DEFAULT: Self = None;
 }
 
+#[cfg(all(feature = "alloc", feature = "unstable"))]
+impl ConstDefault for Global {
+    const DEFAULT: Self = Global;
+}
+
 #[cfg(feature = "alloc")]
 impl ConstDefault for String {
     const DEFAULT: Self = Self::new();
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", not(feature = "unstable")))]
 impl<T> ConstDefault for Vec<T> {
     const DEFAULT: Self = Self::new();
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", feature = "unstable"))]
+impl<T, A> ConstDefault for Vec<T, A>
+where
+    A: Allocator + ConstDefault,
+{
+    const DEFAULT: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "alloc", not(feature = "unstable")))]
 impl<T> ConstDefault for LinkedList<T> {
     const DEFAULT: Self = Self::new();
 }
 
+#[cfg(all(feature = "alloc", feature = "unstable"))]
+impl<T, A> ConstDefault for LinkedList<T, A>
+where
+    A: Allocator + ConstDefault,
+{
+    const DEFAULT: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
 impl<T> ConstDefault for Cell<T>
 where
     T: ConstDefault,
@@ -261,10 +529,131 @@ where
     const DEFAULT: Self = Self(<T as ConstDefault>::DEFAULT);
 }
 
+#[cfg(has_saturating)]
+impl<T> ConstDefault for Saturating<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self(<T as ConstDefault>::DEFAULT);
+}
+
+impl<T> ConstDefault for Reverse<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self(<T as ConstDefault>::DEFAULT);
+}
+
+#[cfg(has_once_cell)]
+impl<T> ConstDefault for OnceCell<T> {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(feature = "unstable")]
+impl<T> ConstDefault for SyncUnsafeCell<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self::new(<T as ConstDefault>::DEFAULT);
+}
+
+/// Formerly known as `core::sync::Exclusive`.
+#[cfg(feature = "unstable")]
+impl<T> ConstDefault for SyncView<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self::new(<T as ConstDefault>::DEFAULT);
+}
+
+/// Defaults to a vector with all lanes set to the default of `T`.
+#[cfg(feature = "unstable")]
+impl<T, const N: usize> ConstDefault for Simd<T, N>
+where
+    T: SimdElement + ConstDefault,
+{
+    const DEFAULT: Self = Self::splat(<T as ConstDefault>::DEFAULT);
+}
+
+impl<T> ConstDefault for Range<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self =
+        <T as ConstDefault>::DEFAULT..<T as ConstDefault>::DEFAULT;
+}
+
+impl<T> ConstDefault for RangeFrom<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = <T as ConstDefault>::DEFAULT..;
+}
+
+impl<T> ConstDefault for RangeTo<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = ..<T as ConstDefault>::DEFAULT;
+}
+
+impl ConstDefault for RangeFull {
+    const DEFAULT: Self = ..;
+}
+
+/// Defaults to [`Bound::Unbounded`].
+#[cfg(feature = "neutral-values")]
+impl<T> ConstDefault for Bound<T> {
+    const DEFAULT: Self = Self::Unbounded;
+}
+
+/// Defaults to [`Ipv4Addr::UNSPECIFIED`].
+#[cfg(all(feature = "neutral-values", has_core_net))]
+impl ConstDefault for Ipv4Addr {
+    const DEFAULT: Self = Self::UNSPECIFIED;
+}
+
+/// Defaults to [`Ipv6Addr::UNSPECIFIED`].
+#[cfg(all(feature = "neutral-values", has_core_net))]
+impl ConstDefault for Ipv6Addr {
+    const DEFAULT: Self = Self::UNSPECIFIED;
+}
+
+/// Defaults to the unspecified IPv4 address.
+#[cfg(all(feature = "neutral-values", has_core_net))]
+impl ConstDefault for IpAddr {
+    const DEFAULT: Self = Self::V4(<Ipv4Addr as ConstDefault>::DEFAULT);
+}
+
+/// Defaults to the unspecified IPv4 address with port 0.
+#[cfg(all(feature = "neutral-values", has_core_net))]
+impl ConstDefault for SocketAddrV4 {
+    const DEFAULT: Self = Self::new(<Ipv4Addr as ConstDefault>::DEFAULT, 0);
+}
+
+/// Defaults to the unspecified IPv6 address with port 0, no flow info and no scope.
+#[cfg(all(feature = "neutral-values", has_core_net))]
+impl ConstDefault for SocketAddrV6 {
+    const DEFAULT: Self =
+        Self::new(<Ipv6Addr as ConstDefault>::DEFAULT, 0, 0, 0);
+}
+
+/// Defaults to the unspecified IPv4 address with port 0.
+#[cfg(all(feature = "neutral-values", has_core_net))]
+impl ConstDefault for SocketAddr {
+    const DEFAULT: Self = Self::V4(<SocketAddrV4 as ConstDefault>::DEFAULT);
+}
+
 impl ConstDefault for Duration {
     const DEFAULT: Self = Self::from_secs(0);
 }
 
+/// Defaults to [`SystemTime::UNIX_EPOCH`].
+#[cfg(all(feature = "std", feature = "neutral-values"))]
+impl ConstDefault for SystemTime {
+    const DEFAULT: Self = Self::UNIX_EPOCH;
+}
+
 impl<T> ConstDefault for Empty<T> {
     const DEFAULT: Self = iter::empty();
 }
@@ -279,16 +668,113 @@ where
         Self::Owned(<<T as ToOwned>::Owned as ConstDefault>::DEFAULT);
 }
 
-#[cfg(all(feature = "alloc", feature = "unstable"))]
+#[cfg(all(feature = "alloc", has_const_btree_new))]
 impl<K: Ord, V> ConstDefault for BTreeMap<K, V> {
     const DEFAULT: Self = Self::new();
 }
 
-#[cfg(all(feature = "alloc", feature = "unstable"))]
+#[cfg(all(feature = "alloc", has_const_btree_new))]
 impl<T: Ord> ConstDefault for BTreeSet<T> {
     const DEFAULT: Self = Self::new();
 }
 
+#[cfg(all(
+    feature = "alloc",
+    has_const_vec_deque_new,
+    not(feature = "unstable")
+))]
+impl<T> ConstDefault for VecDeque<T> {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", has_const_vec_deque_new, feature = "unstable"))]
+impl<T, A> ConstDefault for VecDeque<T, A>
+where
+    A: Allocator + ConstDefault,
+{
+    const DEFAULT: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(
+    feature = "alloc",
+    has_const_binary_heap_new,
+    not(feature = "unstable")
+))]
+impl<T: Ord> ConstDefault for BinaryHeap<T> {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", has_const_binary_heap_new, feature = "unstable"))]
+impl<T, A> ConstDefault for BinaryHeap<T, A>
+where
+    T: Ord,
+    A: Allocator + ConstDefault,
+{
+    const DEFAULT: Self = Self::new_in(<A as ConstDefault>::DEFAULT);
+}
+
+#[cfg(has_const_hash_map_with_hasher)]
+impl<H> ConstDefault for BuildHasherDefault<H> {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+impl<K, V, S> ConstDefault for HashMap<K, V, S>
+where
+    S: ConstDefault,
+{
+    const DEFAULT: Self = Self::with_hasher(<S as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+impl<T, S> ConstDefault for HashSet<T, S>
+where
+    S: ConstDefault,
+{
+    const DEFAULT: Self = Self::with_hasher(<S as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "std", has_const_mutex_new))]
+impl<T> ConstDefault for Mutex<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self::new(<T as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "std", has_const_mutex_new))]
+impl<T> ConstDefault for RwLock<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self::new(<T as ConstDefault>::DEFAULT);
+}
+
+#[cfg(all(feature = "std", has_const_mutex_new))]
+impl ConstDefault for Condvar {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(feature = "std")]
+impl ConstDefault for Once {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(all(feature = "std", has_once_cell))]
+impl<T> ConstDefault for OnceLock<T> {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", has_const_weak_new))]
+impl<T> ConstDefault for rc::Weak<T> {
+    const DEFAULT: Self = Self::new();
+}
+
+#[cfg(all(feature = "alloc", has_const_weak_new, target_has_atomic = "ptr"))]
+impl<T> ConstDefault for sync::Weak<T> {
+    const DEFAULT: Self = Self::new();
+}
+
 impl<T> ConstDefault for PhantomData<T> {
     const DEFAULT: Self = Self;
 }
@@ -297,6 +783,33 @@ impl ConstDefault for PhantomPinned {
     const DEFAULT: Self = Self;
 }
 
+impl ConstDefault for fmt::Error {
+    const DEFAULT: Self = Self;
+}
+
+#[cfg(feature = "std")]
+impl ConstDefault for System {
+    const DEFAULT: Self = Self;
+}
+
+#[cfg(all(feature = "std", has_const_io_structs))]
+impl ConstDefault for io::Empty {
+    const DEFAULT: Self = io::empty();
+}
+
+#[cfg(all(feature = "std", has_const_io_structs))]
+impl ConstDefault for io::Sink {
+    const DEFAULT: Self = io::sink();
+}
+
+#[cfg(all(feature = "std", has_const_io_structs))]
+impl<T> ConstDefault for io::Cursor<T>
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = Self::new(<T as ConstDefault>::DEFAULT);
+}
+
 impl<T> ConstDefault for *const T {
     const DEFAULT: Self = ptr::null();
 }
@@ -0,0 +1,143 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    num::{
+        NonZeroI128,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI8,
+        NonZeroIsize,
+        NonZeroU128,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU8,
+        NonZeroUsize,
+        Wrapping,
+    },
+    time::Duration,
+};
+
+#[cfg(has_saturating)]
+use core::num::Saturating;
+
+/// Implements a compilation time additive identity for the implemented type.
+///
+/// # Note
+///
+/// Unlike [`ConstDefault`](crate::ConstDefault) this explicitly denotes the
+/// zero value of a numeric type and does not rely on the default value
+/// of a type being zero.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstZero;
+/// const ZERO: f32 = <f32 as ConstZero>::ZERO;
+/// assert_eq!(ZERO + 1.5, 1.5);
+/// ```
+pub trait ConstZero {
+    /// The constant additive identity.
+    const ZERO: Self;
+}
+
+/// Implements a compilation time multiplicative identity for the implemented type.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstOne;
+/// const ONE: u32 = <u32 as ConstOne>::ONE;
+/// assert_eq!(ONE * 42, 42);
+/// ```
+pub trait ConstOne {
+    /// The constant multiplicative identity.
+    const ONE: Self;
+}
+
+macro_rules! impl_const_zero_one_for_primitive {
+    ( $zero:literal, $one:literal; $( $prim:ty ),* ) => {
+        $(
+            impl ConstZero for $prim {
+                const ZERO: Self = $zero;
+            }
+
+            impl ConstOne for $prim {
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+impl_const_zero_one_for_primitive!(
+    0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_const_zero_one_for_primitive!(0.0, 1.0; f32, f64);
+#[cfg(feature = "unstable")]
+impl_const_zero_one_for_primitive!(0.0, 1.0; f16, f128);
+
+macro_rules! impl_const_one_for_non_zero {
+    ( $( $non_zero:ty ),* ) => {
+        $(
+            impl ConstOne for $non_zero {
+                const ONE: Self = match Self::new(1) {
+                    Some(one) => one,
+                    None => panic!("one is non-zero"),
+                };
+            }
+        )*
+    };
+}
+impl_const_one_for_non_zero!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+macro_rules! impl_const_zero_one_for_wrapper {
+    ( $( $wrapper:ident ),* ) => {
+        $(
+            impl<T> ConstZero for $wrapper<T>
+            where
+                T: ConstZero,
+            {
+                const ZERO: Self = Self(<T as ConstZero>::ZERO);
+            }
+
+            impl<T> ConstOne for $wrapper<T>
+            where
+                T: ConstOne,
+            {
+                const ONE: Self = Self(<T as ConstOne>::ONE);
+            }
+        )*
+    };
+}
+impl_const_zero_one_for_wrapper!(Wrapping);
+#[cfg(has_saturating)]
+impl_const_zero_one_for_wrapper!(Saturating);
+
+impl ConstZero for Duration {
+    const ZERO: Self = Self::ZERO;
+}
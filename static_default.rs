@@ -0,0 +1,98 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Declares statics that are initialized with their [`ConstDefault`] value.
+///
+/// Attributes, doc comments and visibility are passed through to the statics.
+/// Types of statics that are not `static mut` are checked to be `Sync`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use core::sync::atomic::{AtomicUsize, Ordering};
+/// use std::{collections::BTreeMap, sync::Mutex};
+///
+/// const_default::static_default! {
+///     /// The number of handled requests.
+///     pub static REQUESTS: AtomicUsize;
+///     static REGISTRY: Mutex<BTreeMap<u32, String>>;
+///     static mut SCRATCH: [u8; 64];
+/// }
+///
+/// REQUESTS.fetch_add(1, Ordering::Relaxed);
+/// REGISTRY.lock().unwrap().insert(1, String::from("one"));
+/// assert_eq!(REQUESTS.load(Ordering::Relaxed), 1);
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// ```
+///
+/// ```compile_fail
+/// use core::cell::Cell;
+///
+/// const_default::static_default! {
+///     static COUNTER: Cell<u32>;
+/// }
+/// ```
+///
+/// [`ConstDefault`]: crate::ConstDefault
+#[macro_export]
+macro_rules! static_default {
+    () => {};
+    (
+        $( #[$attr:meta] )*
+        $vis:vis static mut $name:ident: $ty:ty;
+        $( $rest:tt )*
+    ) => {
+        $( #[$attr] )*
+        $vis static mut $name: $ty = <$ty as $crate::ConstDefault>::DEFAULT;
+        $crate::static_default!($( $rest )*);
+    };
+    (
+        $( #[$attr:meta] )*
+        $vis:vis static $name:ident: $ty:ty;
+        $( $rest:tt )*
+    ) => {
+        $( #[$attr] )*
+        $vis static $name: $ty = {
+            let _: fn() = $crate::__assert_static_sync::<$ty>;
+            <$ty as $crate::ConstDefault>::DEFAULT
+        };
+        $crate::static_default!($( $rest )*);
+    };
+}
+
+/// Implemented for all types that may be the type of a non-`mut` static.
+#[cfg_attr(
+    has_diagnostic_namespace,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not `Sync` and cannot be the type of a `static`",
+        label = "declared by `static_default!` without `mut`",
+        note = "declare it as `static mut` or wrap it in a synchronization primitive such as `Mutex`"
+    )
+)]
+pub trait StaticSync {}
+
+impl<T> StaticSync for T where T: ?Sized + Sync {}
+
+/// Fails compilation with a readable error if `T` is not `Sync`.
+///
+/// Used by [`static_default!`](crate::static_default!).
+pub fn assert_static_sync<T>()
+where
+    T: ?Sized + StaticSync,
+{
+}
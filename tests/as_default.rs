@@ -0,0 +1,57 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use const_default::{AsDefault, ConstDefault};
+use std::collections::HashMap;
+
+/// A type that implements `ConstDefault` but deliberately not `Default`.
+#[derive(Debug, Clone, PartialEq)]
+struct OnlyConstDefault(u32);
+
+impl ConstDefault for OnlyConstDefault {
+    const DEFAULT: Self = Self(42);
+}
+
+#[test]
+fn default_uses_const_default() {
+    let value = <AsDefault<OnlyConstDefault> as Default>::default();
+    assert_eq!(value.into_inner(), OnlyConstDefault(42));
+    assert_eq!(
+        <AsDefault<OnlyConstDefault> as ConstDefault>::DEFAULT,
+        AsDefault(OnlyConstDefault(42)),
+    );
+}
+
+#[test]
+fn works_with_generic_default_apis() {
+    let mut map = HashMap::<&str, AsDefault<OnlyConstDefault>>::new();
+    for key in ["a", "a", "b"] {
+        let counter: &mut OnlyConstDefault = map.entry(key).or_default();
+        counter.0 += 1;
+    }
+    assert_eq!(map["a"], AsDefault(OnlyConstDefault(44)));
+    assert_eq!(map["b"], AsDefault(OnlyConstDefault(43)));
+}
+
+#[test]
+fn forwards_to_wrapped_value() {
+    let mut value = AsDefault::from(OnlyConstDefault(1));
+    *value = OnlyConstDefault(5);
+    assert_eq!(*value, OnlyConstDefault(5));
+    value.as_mut().0 += 1;
+    assert_eq!(value.as_ref(), &OnlyConstDefault(6));
+    assert_eq!(format!("{:?}", value), "OnlyConstDefault(6)");
+    assert_eq!(format!("{}", AsDefault(7)), "7");
+    assert_eq!(value.clone(), value);
+}
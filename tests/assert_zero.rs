@@ -0,0 +1,56 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(has_const_refs_to_cell)]
+
+use const_default::{assert_zero_default, ConstDefault};
+use core::{
+    cell::Cell,
+    mem::MaybeUninit,
+    num::Wrapping,
+    sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, Ordering},
+};
+
+const _: () = assert_zero_default::<u64>();
+const _: () = assert_zero_default::<f64>();
+const _: () = assert_zero_default::<[u32; 64]>();
+const _: () = assert_zero_default::<Option<&u8>>();
+const _: () = assert_zero_default::<*const u8>();
+const _: () = assert_zero_default::<AtomicPtr<u8>>();
+const _: () = assert_zero_default::<AtomicBool>();
+const _: () = assert_zero_default::<Cell<Wrapping<u16>>>();
+const _: () = assert_zero_default::<MaybeUninit<u32>>();
+
+struct Buffer {
+    len: AtomicU32,
+    data: [u32; 256],
+}
+
+impl ConstDefault for Buffer {
+    const DEFAULT: Self = Self {
+        len: ConstDefault::DEFAULT,
+        data: ConstDefault::DEFAULT,
+    };
+}
+
+const _: () = assert_zero_default::<Buffer>();
+
+static BUFFER: Buffer = Buffer::DEFAULT;
+
+#[test]
+fn runtime_call_works() {
+    assert_zero_default::<Buffer>();
+    assert_eq!(BUFFER.data, [0; 256]);
+    assert_eq!(BUFFER.len.load(Ordering::Relaxed), 0);
+}
@@ -0,0 +1,39 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "alloc")]
+
+use const_default::ConstBorrowed;
+use std::borrow::Cow;
+
+#[test]
+fn cow_impls_work() {
+    assert!(matches!(
+        <Cow<str> as ConstBorrowed>::BORROWED,
+        Cow::Borrowed("")
+    ));
+    assert!(matches!(
+        <Cow<[u8]> as ConstBorrowed>::BORROWED,
+        Cow::Borrowed(&[])
+    ));
+}
+
+#[test]
+#[cfg(has_const_cstr)]
+fn cow_c_str_impl_works() {
+    use std::ffi::CStr;
+
+    const NAME: Cow<CStr> = <Cow<CStr> as ConstBorrowed>::BORROWED;
+    assert!(matches!(NAME, Cow::Borrowed(name) if name.is_empty()));
+}
@@ -0,0 +1,158 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(feature = "unstable", feature(f16, f128))]
+#![allow(clippy::borrow_interior_mutable_const)]
+
+use const_default::{ConstMax, ConstMin};
+use core::{
+    num::Wrapping,
+    sync::atomic::{
+        AtomicBool,
+        AtomicI16,
+        AtomicI32,
+        AtomicI64,
+        AtomicI8,
+        AtomicIsize,
+        AtomicU16,
+        AtomicU32,
+        AtomicU64,
+        AtomicU8,
+        AtomicUsize,
+        Ordering,
+    },
+    time::Duration,
+};
+
+macro_rules! check_bounds_for_primitives {
+    ( $( $prim:ident ),* $(,)? ) => {{
+        $(
+            assert_eq!(<$prim as ConstMin>::MIN, $prim::MIN);
+            assert_eq!(<$prim as ConstMax>::MAX, $prim::MAX);
+        )*
+    }};
+}
+
+macro_rules! check_bounds_for_floats {
+    ( $( $prim:ident ),* $(,)? ) => {{
+        $(
+            assert_eq!(<$prim as ConstMin>::MIN, $prim::NEG_INFINITY);
+            assert_eq!(<$prim as ConstMax>::MAX, $prim::INFINITY);
+        )*
+    }};
+}
+
+#[test]
+fn primitive_impls_work() {
+    #[rustfmt::skip]
+    check_bounds_for_primitives!(
+        i8, i16, i32, i64, i128, isize,
+        u8, u16, u32, u64, u128, usize,
+    );
+    check_bounds_for_floats!(f32, f64);
+    assert_eq!(
+        (<bool as ConstMin>::MIN, <bool as ConstMax>::MAX),
+        (false, true),
+    );
+    assert_eq!(<char as ConstMin>::MIN, '\x00');
+    assert_eq!(<char as ConstMax>::MAX, char::MAX);
+    assert_eq!(<Duration as ConstMin>::MIN, Duration::ZERO);
+    assert_eq!(<Duration as ConstMax>::MAX, Duration::MAX);
+}
+
+#[test]
+fn float_accumulator_works() {
+    let samples = [f64::NEG_INFINITY, f64::NEG_INFINITY];
+    let highest = samples
+        .iter()
+        .fold(<f64 as ConstMin>::MIN, |highest, &sample| {
+            highest.max(sample)
+        });
+    assert_eq!(highest, f64::NEG_INFINITY);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn unstable_float_impls_work() {
+    check_bounds_for_floats!(f16, f128);
+}
+
+#[test]
+fn wrapper_impls_work() {
+    assert_eq!(<Wrapping<u8> as ConstMax>::MAX, Wrapping(u8::MAX));
+    assert_eq!(<Wrapping<i8> as ConstMin>::MIN, Wrapping(i8::MIN));
+}
+
+#[test]
+#[cfg(has_saturating)]
+fn saturating_impls_work() {
+    use core::num::Saturating;
+
+    assert_eq!(<Saturating<u8> as ConstMax>::MAX, Saturating(u8::MAX));
+    assert_eq!(<Saturating<i8> as ConstMin>::MIN, Saturating(i8::MIN));
+}
+
+#[test]
+fn tuple_and_array_impls_work() {
+    assert_eq!(<() as ConstMin>::MIN, ());
+    assert_eq!(<(u8, i16) as ConstMin>::MIN, (u8::MIN, i16::MIN));
+    assert_eq!(<(u8, i16) as ConstMax>::MAX, (u8::MAX, i16::MAX));
+    assert_eq!(<[i8; 3] as ConstMin>::MIN, [i8::MIN; 3]);
+    assert_eq!(
+        <[(u8, char); 2] as ConstMax>::MAX,
+        [(u8::MAX, char::MAX); 2]
+    );
+}
+
+macro_rules! check_bounds_for_atomics {
+    ( $( $atomic_type:ty: $prim:ident ),* $(,)? ) => {{
+        $(
+            assert_eq!(
+                <$atomic_type as ConstMin>::MIN.load(Ordering::SeqCst),
+                $prim::MIN,
+            );
+            assert_eq!(
+                <$atomic_type as ConstMax>::MAX.load(Ordering::SeqCst),
+                $prim::MAX,
+            );
+        )*
+    }};
+}
+
+#[test]
+fn atomic_impls_work() {
+    check_bounds_for_atomics!(
+        AtomicI8: i8,
+        AtomicI16: i16,
+        AtomicI32: i32,
+        AtomicI64: i64,
+        AtomicIsize: isize,
+        AtomicU8: u8,
+        AtomicU16: u16,
+        AtomicU32: u32,
+        AtomicU64: u64,
+        AtomicUsize: usize,
+    );
+    assert!(!<AtomicBool as ConstMin>::MIN.load(Ordering::SeqCst));
+    assert!(<AtomicBool as ConstMax>::MAX.load(Ordering::SeqCst));
+}
+
+#[test]
+fn min_accumulator_works() {
+    static LOWEST: AtomicU64 = <AtomicU64 as ConstMax>::MAX;
+    for latency in [30, 10, 20] {
+        LOWEST.fetch_min(latency, Ordering::Relaxed);
+    }
+    assert_eq!(LOWEST.load(Ordering::Relaxed), 10);
+}
@@ -0,0 +1,62 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "unstable")]
+#![feature(const_trait_impl, const_default, derive_const)]
+
+use const_default::{AsDefault, ConstDefault};
+
+#[derive_const(Default)]
+#[derive(Debug, PartialEq)]
+struct Config {
+    retries: u32,
+    name: Option<&'static str>,
+}
+
+const_default::impl_const_default_from_default!(Config);
+
+struct Counter(u32);
+
+impl ConstDefault for Counter {
+    const DEFAULT: Self = Counter(42);
+}
+
+#[test]
+fn default_fn_works() {
+    const VEC: Vec<u8> = const_default::default();
+    const STRING: String = const_default::default();
+    const CONFIG: Config = const_default::default();
+
+    assert_eq!(VEC, Vec::new());
+    assert_eq!(STRING, String::new());
+    assert_eq!(
+        CONFIG,
+        Config {
+            retries: 0,
+            name: None
+        }
+    );
+}
+
+#[test]
+fn const_default_from_default_works() {
+    assert_eq!(<Config as ConstDefault>::DEFAULT, Config::default());
+}
+
+#[test]
+fn as_default_is_const_default() {
+    const COUNTER: AsDefault<Counter> = const_default::default();
+
+    assert_eq!(COUNTER.into_inner().0, 42);
+}
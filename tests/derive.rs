@@ -183,6 +183,7 @@ fn struct_of_cell_types_works() {
         field_1: Cell<i32>,
         field_2: RefCell<i32>,
     }
+    assert_eq!(<TestStruct as ConstDefault>::DEFAULT, TestStruct::default());
 }
 
 #[test]
@@ -198,3 +199,344 @@ fn type_alias_works() {
     assert_eq!(<TestType1 as ConstDefault>::DEFAULT, TestType1::default());
     assert_eq!(<TestType2 as ConstDefault>::DEFAULT, TestType2::default());
 }
+
+#[test]
+fn newtype_numerics_work() {
+    use const_default::{ConstOne, ConstZero};
+
+    #[derive(ConstZero, ConstOne, Debug, PartialEq)]
+    pub struct Meters(f64);
+    #[derive(ConstZero, ConstOne, Debug, PartialEq)]
+    pub struct Count {
+        value: u32,
+    }
+    #[derive(ConstZero, ConstOne, Debug, PartialEq)]
+    pub struct Generic<T>(T);
+    assert_eq!(<Meters as ConstZero>::ZERO, Meters(0.0));
+    assert_eq!(<Meters as ConstOne>::ONE, Meters(1.0));
+    assert_eq!(<Count as ConstZero>::ZERO, Count { value: 0 });
+    assert_eq!(<Count as ConstOne>::ONE, Count { value: 1 });
+    assert_eq!(<Generic<i8> as ConstZero>::ZERO, Generic(0));
+    assert_eq!(<Generic<i8> as ConstOne>::ONE, Generic(1));
+}
+
+#[test]
+fn fieldwise_bounds_work() {
+    use const_default::{ConstMax, ConstMin};
+
+    #[derive(ConstMin, ConstMax, Debug, PartialEq)]
+    pub struct Limits {
+        count: u32,
+        offset: i8,
+        flags: [bool; 2],
+    }
+    #[derive(ConstMin, ConstMax, Debug, PartialEq)]
+    pub struct Pair(u8, char);
+    assert_eq!(
+        <Limits as ConstMin>::MIN,
+        Limits {
+            count: 0,
+            offset: i8::MIN,
+            flags: [false; 2],
+        },
+    );
+    assert_eq!(
+        <Limits as ConstMax>::MAX,
+        Limits {
+            count: u32::MAX,
+            offset: i8::MAX,
+            flags: [true; 2],
+        },
+    );
+    assert_eq!(<Pair as ConstMin>::MIN, Pair(0, '\x00'));
+    assert_eq!(<Pair as ConstMax>::MAX, Pair(u8::MAX, char::MAX));
+}
+
+#[test]
+fn newtype_collections_work() {
+    use const_default::ConstEmpty;
+
+    #[derive(ConstEmpty, Debug, PartialEq)]
+    pub struct Names(Vec<String>);
+    #[derive(ConstEmpty, Debug, PartialEq)]
+    pub struct Bytes<'a> {
+        bytes: &'a [u8],
+    }
+    assert_eq!(<Names as ConstEmpty>::EMPTY, Names(Vec::new()));
+    assert_eq!(<Bytes as ConstEmpty>::EMPTY, Bytes { bytes: &[] });
+}
+
+#[test]
+#[cfg(all(feature = "std", has_const_mutex_new, has_once_cell))]
+fn struct_of_locks_works() {
+    use std::sync::{Condvar, Mutex, Once, OnceLock, RwLock};
+
+    #[derive(ConstDefault)]
+    pub struct Shared {
+        queue: Mutex<Vec<u32>>,
+        ready: Condvar,
+        config: RwLock<Option<u32>>,
+        init: Once,
+        name: OnceLock<String>,
+    }
+    static SHARED: Shared = <Shared as ConstDefault>::DEFAULT;
+    SHARED.queue.lock().unwrap().push(42);
+    SHARED.ready.notify_one();
+    *SHARED.config.write().unwrap() = Some(1);
+    SHARED.init.call_once(|| ());
+    SHARED.name.get_or_init(|| String::from("shared"));
+    assert_eq!(*SHARED.queue.lock().unwrap(), vec![42]);
+    assert_eq!(*SHARED.config.read().unwrap(), Some(1));
+    assert!(SHARED.init.is_completed());
+    assert_eq!(SHARED.name.get().map(String::as_str), Some("shared"));
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_weak_new))]
+fn struct_of_weak_pointers_works() {
+    use std::rc::{Rc, Weak};
+
+    #[derive(ConstDefault)]
+    pub struct Node {
+        value: u32,
+        parent: RefCell<Weak<Node>>,
+    }
+    let parent = Rc::new(Node {
+        value: 1,
+        ..<Node as ConstDefault>::DEFAULT
+    });
+    let child = <Node as ConstDefault>::DEFAULT;
+    assert!(child.parent.borrow().upgrade().is_none());
+    *child.parent.borrow_mut() = Rc::downgrade(&parent);
+    assert_eq!(
+        child.parent.borrow().upgrade().map(|node| node.value),
+        Some(1)
+    );
+    assert_eq!(child.value, 0);
+}
+
+#[test]
+fn struct_of_ranges_works() {
+    use core::ops::Range;
+
+    #[derive(ConstDefault, Debug, Default, PartialEq)]
+    pub struct Span {
+        bytes: Range<usize>,
+        lines: Range<u32>,
+    }
+    assert_eq!(<Span as ConstDefault>::DEFAULT, Span::default());
+}
+
+#[test]
+#[cfg(all(has_const_mut_refs, has_const_from_utf8_mut, has_const_cstr))]
+fn struct_of_borrowed_slices_works() {
+    use core::ffi::CStr;
+
+    #[derive(ConstDefault, Debug, Default, PartialEq)]
+    pub struct Parser<'a> {
+        input: &'a mut [u8],
+        scratch: &'a mut str,
+        name: &'a CStr,
+    }
+    assert_eq!(<Parser as ConstDefault>::DEFAULT, Parser::default());
+}
+
+#[test]
+fn uninit_fields_work() {
+    use core::mem::MaybeUninit;
+
+    /// A type without a `ConstDefault` implementation.
+    pub struct NoDefault;
+
+    #[derive(ConstDefault)]
+    pub struct Arena<T> {
+        len: usize,
+        #[const_default(uninit)]
+        slots: [MaybeUninit<T>; 64],
+        #[const_default(uninit)]
+        spare: MaybeUninit<T>,
+    }
+    let arena = <Arena<NoDefault> as ConstDefault>::DEFAULT;
+    assert_eq!(arena.len, 0);
+    assert_eq!(arena.slots.len(), 64);
+    let _ = arena.spare;
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_cstr))]
+fn borrowed_fields_work() {
+    use std::{borrow::Cow, ffi::CStr};
+
+    #[derive(ConstDefault)]
+    pub struct Symbol<'a> {
+        #[const_default(borrowed)]
+        name: Cow<'a, CStr>,
+        #[const_default(borrowed)]
+        alias: Cow<'a, str>,
+        owned: Cow<'a, str>,
+    }
+    const SYMBOL: Symbol = <Symbol as ConstDefault>::DEFAULT;
+    assert!(matches!(SYMBOL.name, Cow::Borrowed(name) if name.is_empty()));
+    assert!(matches!(SYMBOL.alias, Cow::Borrowed("")));
+    assert!(matches!(SYMBOL.owned, Cow::Owned(owned) if owned.is_empty()));
+}
+
+#[test]
+fn struct_of_fn_pointers_works() {
+    #[derive(ConstDefault)]
+    pub struct DriverOps {
+        open: fn(u32) -> i32,
+        read: fn(u32, usize) -> Option<u8>,
+        release: unsafe extern "C" fn(*mut u8),
+    }
+    static OPS: DriverOps = <DriverOps as ConstDefault>::DEFAULT;
+    assert_eq!((OPS.open)(1), 0);
+    assert_eq!((OPS.read)(1, 2), None);
+    // SAFETY: the default function does not have any safety requirements.
+    unsafe { (OPS.release)(core::ptr::null_mut()) };
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "neutral-values"))]
+fn telemetry_record_works() {
+    use std::{io::Sink, time::SystemTime};
+
+    #[derive(ConstDefault)]
+    pub struct Record {
+        timestamp: SystemTime,
+        count: u64,
+        error: Option<core::fmt::Error>,
+        output: Sink,
+    }
+    let record = <Record as ConstDefault>::DEFAULT;
+    assert_eq!(record.timestamp, SystemTime::UNIX_EPOCH);
+    assert_eq!(record.count, 0);
+    assert_eq!(record.error, None);
+    let _ = record.output;
+}
+
+#[test]
+#[cfg(all(feature = "std", has_const_io_structs))]
+fn struct_of_cursors_works() {
+    use std::io::{Cursor, Write};
+
+    #[derive(ConstDefault, Debug, Default, PartialEq)]
+    pub struct Capture {
+        log: Cursor<Vec<u8>>,
+        frame: Cursor<[u8; 8]>,
+    }
+    let mut capture = <Capture as ConstDefault>::DEFAULT;
+    assert_eq!(capture, Capture::default());
+    capture.frame.write_all(b"frame").unwrap();
+    assert_eq!(&capture.frame.get_ref()[..5], b"frame");
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn zeroed_struct_works() {
+    #[derive(
+        ConstDefault, Debug, Copy, Clone, PartialEq, bytemuck::Zeroable,
+    )]
+    #[const_default(zeroed(bytemuck))]
+    #[repr(C)]
+    pub struct Registers {
+        control: u32,
+        status: [u8; 4],
+        scale: f64,
+    }
+
+    #[derive(
+        ConstDefault, Debug, Copy, Clone, PartialEq, bytemuck::Zeroable,
+    )]
+    #[const_default(zeroed(bytemuck))]
+    #[repr(transparent)]
+    pub struct Wrapper<T>(T)
+    where
+        T: bytemuck::Zeroable;
+
+    assert_eq!(
+        <Registers as ConstDefault>::DEFAULT,
+        Registers {
+            control: 0,
+            status: [0; 4],
+            scale: 0.0
+        },
+    );
+    assert_eq!(<Wrapper<u16> as ConstDefault>::DEFAULT, Wrapper(0));
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn zerocopy_zeroed_struct_works() {
+    #[derive(ConstDefault, Debug, PartialEq, zerocopy::FromZeros)]
+    #[const_default(zeroed(zerocopy))]
+    pub struct Node {
+        id: u64,
+        next: Option<core::num::NonZeroU64>,
+    }
+
+    assert_eq!(<Node as ConstDefault>::DEFAULT, Node { id: 0, next: None });
+}
+
+#[test]
+#[cfg(has_const_refs_to_cell)]
+fn zero_asserted_struct_works() {
+    use core::{mem::MaybeUninit, sync::atomic::AtomicUsize};
+
+    #[derive(ConstDefault)]
+    #[const_default(assert_zero)]
+    pub struct Heap {
+        next: AtomicUsize,
+        flags: (u16, u16),
+        blocks: [Option<&'static u8>; 8],
+        #[const_default(uninit)]
+        memory: [MaybeUninit<u64>; 512],
+    }
+
+    static HEAP: Heap = Heap::DEFAULT;
+    assert_eq!(HEAP.next.load(core::sync::atomic::Ordering::Relaxed), 0);
+    assert_eq!(HEAP.flags, (0, 0));
+    assert_eq!(HEAP.blocks, [None; 8]);
+    assert_eq!(HEAP.memory.len(), 512);
+}
+
+#[test]
+#[cfg(all(feature = "bytemuck", has_const_refs_to_cell))]
+fn zero_asserted_zeroed_struct_works() {
+    #[derive(
+        ConstDefault, Debug, Copy, Clone, PartialEq, bytemuck::Zeroable,
+    )]
+    #[const_default(zeroed(bytemuck), assert_zero)]
+    #[repr(C)]
+    pub struct Page {
+        used: u32,
+        data: [u8; 32],
+    }
+
+    assert_eq!(
+        <Page as ConstDefault>::DEFAULT,
+        Page {
+            used: 0,
+            data: [0; 32]
+        },
+    );
+}
+
+#[test]
+#[cfg(has_const_refs_to_cell)]
+fn fieldwise_zeroed_struct_works() {
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(ConstDefault)]
+    #[const_default(zeroed)]
+    pub struct Counters<T> {
+        hits: AtomicU32,
+        misses: T,
+        last: Option<&'static u8>,
+    }
+
+    static COUNTERS: Counters<[u64; 4]> = Counters::DEFAULT;
+    assert_eq!(COUNTERS.hits.load(Ordering::Relaxed), 0);
+    assert_eq!(COUNTERS.misses, [0; 4]);
+    assert_eq!(COUNTERS.last, None);
+}
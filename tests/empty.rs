@@ -0,0 +1,99 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(feature = "unstable", feature(allocator_api))]
+
+use const_default::ConstEmpty;
+use core::iter::Empty;
+
+const _: () = assert!(<&str as ConstEmpty>::EMPTY.is_empty());
+const _: () = assert!(<&[u8] as ConstEmpty>::EMPTY.is_empty());
+
+#[test]
+fn borrowed_impls_work() {
+    assert_eq!(<&str as ConstEmpty>::EMPTY, "");
+    assert_eq!(<&[u8] as ConstEmpty>::EMPTY, &[] as &[u8]);
+    assert_eq!(<Empty<u8> as ConstEmpty>::EMPTY.count(), 0);
+}
+
+#[test]
+#[cfg(all(has_const_mut_refs, has_const_from_utf8_mut, has_const_cstr))]
+fn mutable_and_c_string_impls_work() {
+    use core::ffi::CStr;
+
+    assert!(<&mut [u8] as ConstEmpty>::EMPTY.is_empty());
+    assert!(<&mut str as ConstEmpty>::EMPTY.is_empty());
+    assert!(<&CStr as ConstEmpty>::EMPTY.is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn collection_impls_work() {
+    use std::collections::LinkedList;
+
+    assert!(<Vec<u8> as ConstEmpty>::EMPTY.is_empty());
+    assert!(<String as ConstEmpty>::EMPTY.is_empty());
+    assert!(<LinkedList<u8> as ConstEmpty>::EMPTY.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_btree_new))]
+fn btree_impls_work() {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    assert!(<BTreeMap<u8, u8> as ConstEmpty>::EMPTY.is_empty());
+    assert!(<BTreeSet<u8> as ConstEmpty>::EMPTY.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_vec_deque_new))]
+fn vec_deque_impl_works() {
+    use std::collections::VecDeque;
+
+    assert!(<VecDeque<u8> as ConstEmpty>::EMPTY.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_binary_heap_new))]
+fn binary_heap_impl_works() {
+    use std::collections::BinaryHeap;
+
+    assert!(<BinaryHeap<u8> as ConstEmpty>::EMPTY.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+fn hash_impls_work() {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap, HashSet},
+        hash::BuildHasherDefault,
+    };
+
+    type Hasher = BuildHasherDefault<DefaultHasher>;
+    assert!(<HashMap<u8, u8, Hasher> as ConstEmpty>::EMPTY.is_empty());
+    assert!(<HashSet<u8, Hasher> as ConstEmpty>::EMPTY.is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unstable"))]
+fn allocator_collection_impls_work() {
+    use std::{
+        alloc::System,
+        collections::{LinkedList, VecDeque},
+    };
+
+    assert!(<Vec<u8, System> as ConstEmpty>::EMPTY.is_empty());
+    assert!(<VecDeque<u8, System> as ConstEmpty>::EMPTY.is_empty());
+    assert!(<LinkedList<u8, System> as ConstEmpty>::EMPTY.is_empty());
+}
@@ -0,0 +1,69 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(has_once_cell)]
+
+use const_default::{ConstDefault, LazyDefault};
+use std::{collections::HashMap, path::PathBuf};
+
+#[test]
+fn initializes_on_first_access() {
+    let lazy = <LazyDefault<PathBuf> as ConstDefault>::DEFAULT;
+    assert_eq!(LazyDefault::get(&lazy), None);
+    assert_eq!(LazyDefault::force(&lazy), &PathBuf::default());
+    assert_eq!(LazyDefault::get(&lazy), Some(&PathBuf::default()));
+}
+
+#[test]
+fn mutation_works() {
+    let mut lazy = LazyDefault::<HashMap<u32, u32>>::new();
+    lazy.insert(1, 2);
+    *LazyDefault::force_mut(&mut lazy).entry(1).or_default() += 1;
+    assert_eq!(lazy.get(&1), Some(&3));
+    assert_eq!(
+        LazyDefault::into_inner(lazy),
+        [(1, 3)].iter().copied().collect(),
+    );
+}
+
+#[test]
+fn into_inner_initializes() {
+    let lazy = LazyDefault::<Vec<u8>>::new();
+    assert_eq!(LazyDefault::into_inner(lazy), Vec::new());
+    let lazy = LazyDefault::from(vec![1, 2, 3]);
+    assert_eq!(LazyDefault::into_inner(lazy), vec![1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn static_works() {
+    static MAP: LazyDefault<HashMap<String, PathBuf>> = LazyDefault::new();
+    assert!(MAP.is_empty());
+    assert!(LazyDefault::get(&MAP).is_some());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "derive"))]
+fn derive_works() {
+    #[derive(ConstDefault)]
+    pub struct Config {
+        verbose: bool,
+        paths: LazyDefault<Vec<PathBuf>>,
+        names: LazyDefault<HashMap<u32, String>>,
+    }
+    static CONFIG: Config = <Config as ConstDefault>::DEFAULT;
+    assert!(!CONFIG.verbose);
+    assert!(CONFIG.paths.is_empty());
+    assert!(CONFIG.names.is_empty());
+}
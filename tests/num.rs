@@ -0,0 +1,119 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(feature = "unstable", feature(f16, f128))]
+
+use const_default::{ConstOne, ConstZero};
+use core::{
+    fmt::Debug,
+    num::{
+        NonZeroI128,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI8,
+        NonZeroIsize,
+        NonZeroU128,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU8,
+        NonZeroUsize,
+        Wrapping,
+    },
+    time::Duration,
+};
+
+/// Checks if `ZERO` and `ONE` are the additive and multiplicative identities.
+fn check_identities<T>(value: T)
+where
+    T: ConstZero
+        + ConstOne
+        + Copy
+        + PartialEq
+        + Debug
+        + core::ops::Add<Output = T>
+        + core::ops::Mul<Output = T>,
+{
+    assert_eq!(value + <T as ConstZero>::ZERO, value);
+    assert_eq!(value * <T as ConstOne>::ONE, value);
+}
+
+macro_rules! check_identities_for {
+    ( $( $value:expr ),* $(,)? ) => {{
+        $(
+            check_identities($value);
+        )*
+    }};
+}
+
+#[test]
+fn primitive_impls_work() {
+    #[rustfmt::skip]
+    check_identities_for!(
+        42_i8, 42_i16, 42_i32, 42_i64, 42_i128, 42_isize,
+        42_u8, 42_u16, 42_u32, 42_u64, 42_u128, 42_usize,
+        4.2_f32, 4.2_f64,
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn unstable_float_impls_work() {
+    check_identities_for!(4.2_f16, 4.2_f128);
+}
+
+#[test]
+fn wrapper_impls_work() {
+    check_identities_for!(Wrapping(42_u8), Wrapping(42_i64));
+}
+
+#[test]
+#[cfg(has_saturating)]
+fn saturating_impls_work() {
+    use core::num::Saturating;
+
+    check_identities_for!(Saturating(42_i32), Saturating(42_u64));
+}
+
+macro_rules! check_non_zero_one_for {
+    ( $( $non_zero:ty ),* $(,)? ) => {{
+        $(
+            assert_eq!(<$non_zero as ConstOne>::ONE.get(), 1);
+        )*
+    }};
+}
+
+#[test]
+fn non_zero_impls_work() {
+    check_non_zero_one_for!(
+        NonZeroI8,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize,
+    );
+}
+
+#[test]
+fn duration_impl_works() {
+    assert_eq!(<Duration as ConstZero>::ZERO, Duration::from_secs(0));
+}
@@ -0,0 +1,64 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    cell::Cell,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+mod registry {
+    const_default::static_default! {
+        /// Visible outside of the module.
+        #[cfg(feature = "std")]
+        pub static ENTRIES: std::sync::Mutex<Vec<&'static str>>;
+        pub(crate) static HITS: core::sync::atomic::AtomicU32;
+    }
+}
+
+const_default::static_default! {
+    static REQUESTS: AtomicUsize;
+    #[cfg_attr(target_os = "linux", link_section = ".noinit")]
+    static BYTES: AtomicU64;
+    #[cfg(feature = "std")]
+    static CACHE: std::sync::Mutex<Option<(u32, String)>>;
+    #[allow(dead_code)]
+    static mut LAST_ERROR: Cell<Option<u32>>;
+    static SIGNATURE: [u8; 4];
+    #[cfg(any())]
+    static DISABLED: Cell<u32>;
+    #[cfg(any())]
+    static UNRESOLVED: missing::Type;
+}
+
+#[test]
+fn static_default_works() {
+    REQUESTS.fetch_add(2, Ordering::Relaxed);
+    BYTES.fetch_add(512, Ordering::Relaxed);
+    registry::HITS.fetch_add(1, Ordering::Relaxed);
+
+    assert_eq!(REQUESTS.load(Ordering::Relaxed), 2);
+    assert_eq!(BYTES.load(Ordering::Relaxed), 512);
+    assert_eq!(registry::HITS.load(Ordering::Relaxed), 1);
+    assert_eq!(SIGNATURE, [0; 4]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn static_default_std_works() {
+    *CACHE.lock().unwrap() = Some((1, String::from("cached")));
+    registry::ENTRIES.lock().unwrap().push("entry");
+
+    assert_eq!(CACHE.lock().unwrap().as_ref().unwrap().1, "cached");
+    assert_eq!(*registry::ENTRIES.lock().unwrap(), ["entry"]);
+}
@@ -12,10 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(
+    feature = "unstable",
+    feature(
+        allocator_api,
+        exclusive_wrapper,
+        sync_unsafe_cell,
+        f16,
+        f128,
+        portable_simd
+    )
+)]
+#![cfg_attr(
+    all(feature = "unstable", target_has_atomic = "128"),
+    feature(integer_atomics)
+)]
+#![allow(clippy::borrow_interior_mutable_const)]
+
 use const_default::ConstDefault;
 use core::{
     cell::{Cell, RefCell},
+    cmp::Reverse,
     fmt::Debug,
+    ops::{Range, RangeFrom, RangeFull, RangeTo},
     sync::atomic::{
         AtomicBool,
         AtomicI16,
@@ -153,3 +172,358 @@ fn cell_impls_work() {
         RefCell<RefCell<u8>>,
     );
 }
+
+#[test]
+#[cfg(has_once_cell)]
+fn once_cell_impl_works() {
+    use core::cell::OnceCell;
+
+    compare_default_impls_for!(OnceCell<u8>, OnceCell<(u8, u16)>);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn unstable_cell_impls_work() {
+    use core::{cell::SyncUnsafeCell, sync::SyncView};
+
+    compare_default_impls_for_cells!(
+        SyncUnsafeCell<u8>,
+        SyncUnsafeCell<(u8, u16)>,
+        SyncView<u8>,
+        SyncView<(u8, u16)>,
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn unstable_numeric_impls_work() {
+    use core::simd::Simd;
+
+    compare_default_impls_for!(
+        f16,
+        f128,
+        Simd<u8, 16>,
+        Simd<f32, 4>,
+        Simd<i64, 2>,
+    );
+}
+
+#[test]
+#[cfg(all(feature = "unstable", target_has_atomic = "128"))]
+fn atomic_128_impls_work() {
+    use core::sync::atomic::{AtomicI128, AtomicU128};
+
+    compare_default_impls_for_cells!(AtomicI128, AtomicU128);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unstable"))]
+fn allocator_collection_impls_work() {
+    use std::{
+        alloc::{Global, System},
+        collections::{BinaryHeap, LinkedList, VecDeque},
+        sync::Mutex,
+    };
+
+    static VEC: Mutex<Vec<u8, System>> = Mutex::new(ConstDefault::DEFAULT);
+
+    VEC.lock().unwrap().extend_from_slice(b"arena");
+    assert_eq!(&VEC.lock().unwrap()[..], b"arena");
+    assert!(<Vec<u8, Global> as ConstDefault>::DEFAULT.is_empty());
+    assert!(<VecDeque<u8, System> as ConstDefault>::DEFAULT.is_empty());
+    assert!(<LinkedList<u8, System> as ConstDefault>::DEFAULT.is_empty());
+    assert!(<BinaryHeap<u8, System> as ConstDefault>::DEFAULT.is_empty());
+}
+
+#[test]
+fn wrapper_impls_work() {
+    compare_default_impls_for!(
+        Reverse<u8>,
+        Reverse<(u8, u16)>,
+        core::num::Wrapping<u8>,
+    );
+}
+
+#[test]
+#[cfg(has_saturating)]
+fn saturating_impl_works() {
+    use core::num::Saturating;
+
+    compare_default_impls_for!(Saturating<u8>, Saturating<i64>);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_btree_new))]
+fn btree_impls_work() {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    compare_default_impls_for!(BTreeMap<u8, u16>, BTreeSet<u8>);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_vec_deque_new))]
+fn vec_deque_impl_works() {
+    use std::collections::VecDeque;
+
+    compare_default_impls_for!(VecDeque<u8>, VecDeque<(u8, u16)>);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_binary_heap_new))]
+fn binary_heap_impl_works() {
+    use std::collections::BinaryHeap;
+
+    // `BinaryHeap` does not implement `PartialEq`.
+    assert_eq!(
+        <BinaryHeap<u8> as ConstDefault>::DEFAULT.into_vec(),
+        <BinaryHeap<u8> as Default>::default().into_vec(),
+    );
+}
+
+#[test]
+#[cfg(all(feature = "std", has_const_hash_map_with_hasher))]
+fn hash_impls_work() {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap, HashSet},
+        hash::BuildHasherDefault,
+        sync::Mutex,
+    };
+
+    type Hasher = BuildHasherDefault<DefaultHasher>;
+    compare_default_impls_for!(
+        HashMap<u8, u16, Hasher>,
+        HashSet<u8, Hasher>,
+    );
+    static MAP: Mutex<HashMap<u8, u16, Hasher>> =
+        Mutex::new(<HashMap<u8, u16, Hasher> as ConstDefault>::DEFAULT);
+    MAP.lock().unwrap().insert(1, 2);
+    assert_eq!(MAP.lock().unwrap().get(&1), Some(&2));
+}
+
+#[test]
+#[cfg(all(feature = "std", has_const_mutex_new))]
+fn lock_impls_work() {
+    use std::sync::{Condvar, Mutex, RwLock};
+
+    assert_eq!(
+        <Mutex<u8> as ConstDefault>::DEFAULT.into_inner().unwrap(),
+        <Mutex<u8> as Default>::default().into_inner().unwrap(),
+    );
+    assert_eq!(
+        <RwLock<(u8, u16)> as ConstDefault>::DEFAULT
+            .into_inner()
+            .unwrap(),
+        <RwLock<(u8, u16)> as Default>::default()
+            .into_inner()
+            .unwrap(),
+    );
+    let condvar = <Condvar as ConstDefault>::DEFAULT;
+    condvar.notify_all();
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn once_impl_works() {
+    use std::sync::Once;
+
+    let once = <Once as ConstDefault>::DEFAULT;
+    assert!(!once.is_completed());
+    once.call_once(|| ());
+    assert!(once.is_completed());
+}
+
+#[test]
+#[cfg(all(feature = "std", has_once_cell))]
+fn once_lock_impl_works() {
+    use std::sync::OnceLock;
+
+    compare_default_impls_for!(OnceLock<u8>);
+}
+
+#[test]
+#[cfg(all(feature = "alloc", has_const_weak_new))]
+fn weak_impls_work() {
+    use std::{rc, sync};
+
+    let weak = <rc::Weak<u8> as ConstDefault>::DEFAULT;
+    assert!(weak.upgrade().is_none());
+    assert_eq!(weak.strong_count(), 0);
+    let weak = <sync::Weak<u8> as ConstDefault>::DEFAULT;
+    assert!(weak.upgrade().is_none());
+    assert_eq!(weak.strong_count(), 0);
+}
+
+#[test]
+fn range_impls_work() {
+    compare_default_impls_for!(Range<u8>, Range<(u8, u16)>, RangeFull);
+    assert_eq!(<RangeFrom<u8> as ConstDefault>::DEFAULT, 0..);
+    assert_eq!(<RangeTo<i32> as ConstDefault>::DEFAULT, ..0);
+}
+
+#[test]
+#[cfg(feature = "neutral-values")]
+fn bound_impl_works() {
+    use core::ops::Bound;
+
+    assert_eq!(<Bound<u8> as ConstDefault>::DEFAULT, Bound::Unbounded);
+}
+
+#[test]
+fn borrowed_impls_work() {
+    compare_default_impls_for!(&[u8], &[(u8, u16)], &str);
+}
+
+#[test]
+#[cfg(has_const_mut_refs)]
+fn mutable_slice_impl_works() {
+    compare_default_impls_for!(&mut [u8], &mut [String]);
+}
+
+#[test]
+#[cfg(all(has_const_mut_refs, has_const_from_utf8_mut))]
+fn mutable_str_impl_works() {
+    compare_default_impls_for!(&mut str);
+    let empty = <&mut str as ConstDefault>::DEFAULT;
+    empty.make_ascii_uppercase();
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[cfg(has_const_cstr)]
+fn c_str_impl_works() {
+    use core::ffi::CStr;
+
+    compare_default_impls_for!(&CStr);
+    assert_eq!(<&CStr as ConstDefault>::DEFAULT.to_bytes_with_nul(), b"\0");
+}
+
+#[test]
+fn fn_pointer_impls_work() {
+    <fn() as ConstDefault>::DEFAULT();
+    assert_eq!(<fn(u8) -> u32 as ConstDefault>::DEFAULT(42), 0);
+    assert_eq!(
+        <fn(String, Vec<u8>) -> Option<u8> as ConstDefault>::DEFAULT(
+            String::from("ignored"),
+            vec![1, 2, 3],
+        ),
+        None,
+    );
+    #[rustfmt::skip]
+    let twelve = <fn(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) -> (u8, bool)
+        as ConstDefault>::DEFAULT;
+    assert_eq!(twelve(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12), (0, false));
+    // SAFETY: the default function does not have any safety requirements.
+    let unsafe_result =
+        unsafe { <unsafe fn(u8) -> i32 as ConstDefault>::DEFAULT(1) };
+    assert_eq!(unsafe_result, 0);
+    assert_eq!(
+        <extern "C" fn(i32, i32) -> i32 as ConstDefault>::DEFAULT(1, 2),
+        0,
+    );
+    // SAFETY: the default function does not have any safety requirements.
+    let unsafe_c_result = unsafe {
+        <unsafe extern "C" fn(*const u8) -> usize as ConstDefault>::DEFAULT(
+            core::ptr::null(),
+        )
+    };
+    assert_eq!(unsafe_c_result, 0);
+}
+
+#[test]
+#[cfg(all(feature = "neutral-values", has_core_net))]
+fn net_impls_work() {
+    use core::net::{
+        IpAddr,
+        Ipv4Addr,
+        Ipv6Addr,
+        SocketAddr,
+        SocketAddrV4,
+        SocketAddrV6,
+    };
+
+    assert_eq!(<Ipv4Addr as ConstDefault>::DEFAULT, Ipv4Addr::UNSPECIFIED);
+    assert_eq!(<Ipv6Addr as ConstDefault>::DEFAULT, Ipv6Addr::UNSPECIFIED);
+    assert_eq!(
+        <IpAddr as ConstDefault>::DEFAULT,
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+    );
+    assert_eq!(
+        <SocketAddrV4 as ConstDefault>::DEFAULT,
+        SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0),
+    );
+    assert_eq!(
+        <SocketAddrV6 as ConstDefault>::DEFAULT,
+        SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0),
+    );
+    assert_eq!(
+        <SocketAddr as ConstDefault>::DEFAULT,
+        "0.0.0.0:0".parse::<SocketAddr>().unwrap(),
+    );
+}
+
+#[test]
+fn unit_like_impls_work() {
+    compare_default_impls_for!(
+        core::fmt::Error,
+        core::marker::PhantomData<u8>,
+        core::marker::PhantomPinned,
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn system_allocator_impl_works() {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    let system = <System as ConstDefault>::DEFAULT;
+    let layout = Layout::new::<u64>();
+    // SAFETY: the layout has a non-zero size and the allocation is freed
+    //         with the same layout.
+    unsafe {
+        let ptr = system.alloc(layout);
+        assert!(!ptr.is_null());
+        system.dealloc(ptr, layout);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "std", has_const_io_structs))]
+fn io_impls_work() {
+    use std::io::{self, Read, Write};
+
+    let mut empty = <io::Empty as ConstDefault>::DEFAULT;
+    let mut sink = <io::Sink as ConstDefault>::DEFAULT;
+    let mut buffer = Vec::new();
+    assert_eq!(empty.read_to_end(&mut buffer).unwrap(), 0);
+    assert_eq!(sink.write(b"ignored").unwrap(), 7);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "neutral-values"))]
+fn system_time_impl_works() {
+    use std::time::SystemTime;
+
+    assert_eq!(
+        <SystemTime as ConstDefault>::DEFAULT,
+        SystemTime::UNIX_EPOCH,
+    );
+}
+
+#[test]
+#[cfg(all(feature = "std", has_const_io_structs))]
+fn cursor_impl_works() {
+    use std::io::{Cursor, Write};
+
+    compare_default_impls_for!(
+        Cursor<Vec<u8>>,
+        Cursor<&[u8]>,
+        Cursor<[u8; 16]>,
+    );
+    let mut log = <Cursor<Vec<u8>> as ConstDefault>::DEFAULT;
+    log.write_all(b"captured").unwrap();
+    assert_eq!(log.into_inner(), b"captured");
+    let mut frame = <Cursor<[u8; 4]> as ConstDefault>::DEFAULT;
+    frame.write_all(&[1, 2]).unwrap();
+    assert_eq!(frame.position(), 2);
+    assert_eq!(frame.into_inner(), [1, 2, 0, 0]);
+}
@@ -0,0 +1,45 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use const_default::ConstUninit;
+use core::mem::MaybeUninit;
+
+/// A type without a `ConstDefault` implementation.
+struct NoDefault(String);
+
+#[test]
+fn maybe_uninit_impl_works() {
+    let mut slot = <MaybeUninit<NoDefault> as ConstUninit>::UNINIT;
+    let value = slot.write(NoDefault(String::from("written")));
+    assert_eq!(value.0, "written");
+    // SAFETY: the value has been written above.
+    let value = unsafe { slot.assume_init() };
+    assert_eq!(value.0, "written");
+}
+
+#[test]
+fn array_impl_works() {
+    static mut BUFFER: [MaybeUninit<u64>; 1024] =
+        <[MaybeUninit<u64>; 1024] as ConstUninit>::UNINIT;
+    let mut slots = <[MaybeUninit<NoDefault>; 4] as ConstUninit>::UNINIT;
+    assert_eq!(slots.len(), 4);
+    slots[2].write(NoDefault(String::from("slot")));
+    // SAFETY: the slot has been written above.
+    assert_eq!(unsafe { slots[2].assume_init_ref() }.0, "slot");
+    // SAFETY: the slot has been written above.
+    unsafe { slots[2].assume_init_drop() };
+    // SAFETY: this is the only access to the static.
+    let buffer = unsafe { &mut *core::ptr::addr_of_mut!(BUFFER) };
+    assert_eq!(buffer.len(), 1024);
+}
@@ -0,0 +1,74 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+
+use const_default::{ConstDefault, ConstZeroed};
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytemuck_impls_work() {
+    use const_default::ViaBytemuck;
+
+    #[derive(Debug, Copy, Clone, PartialEq, bytemuck::Zeroable)]
+    #[repr(C)]
+    struct Sample {
+        channels: [i16; 8],
+        gain: f32,
+    }
+
+    impl ConstDefault for Sample {
+        const DEFAULT: Self = <Self as ConstZeroed<ViaBytemuck>>::ZEROED;
+    }
+
+    const SAMPLES: [Sample; 4] = [<Sample as ConstDefault>::DEFAULT; 4];
+    for sample in SAMPLES {
+        assert_eq!(
+            sample,
+            Sample {
+                channels: [0; 8],
+                gain: 0.0
+            }
+        );
+    }
+    assert_eq!(<u64 as ConstZeroed<ViaBytemuck>>::ZEROED, 0);
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn zerocopy_impls_work() {
+    use const_default::ViaZerocopy;
+    use core::num::NonZeroU32;
+
+    #[derive(Debug, PartialEq, zerocopy::FromZeros)]
+    struct Entry {
+        valid: bool,
+        key: u32,
+        next: Option<NonZeroU32>,
+    }
+
+    impl ConstDefault for Entry {
+        const DEFAULT: Self = <Self as ConstZeroed<ViaZerocopy>>::ZEROED;
+    }
+
+    assert_eq!(
+        <Entry as ConstDefault>::DEFAULT,
+        Entry {
+            valid: false,
+            key: 0,
+            next: None
+        }
+    );
+    assert_eq!(<char as ConstZeroed<ViaZerocopy>>::ZEROED, '\0');
+}
@@ -0,0 +1,53 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::mem::MaybeUninit;
+
+/// Implements a compilation time uninitialized value for the implemented type.
+///
+/// # Note
+///
+/// Unlike the [`ConstDefault`](crate::ConstDefault) implementation of
+/// [`MaybeUninit<T>`] this does not require `T: ConstDefault` and does not
+/// initialize the value. This is useful for large buffers and arena storage.
+///
+/// Use `#[const_default(uninit)]` on fields of types deriving `ConstDefault`
+/// to default them via this trait.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::ConstUninit;
+/// use core::mem::MaybeUninit;
+///
+/// struct Page([u8; 4096]);
+///
+/// const BUFFER: [MaybeUninit<Page>; 16] =
+///     <[MaybeUninit<Page>; 16] as ConstUninit>::UNINIT;
+/// ```
+pub trait ConstUninit {
+    /// The constant uninitialized value.
+    const UNINIT: Self;
+}
+
+impl<T> ConstUninit for MaybeUninit<T> {
+    const UNINIT: Self = Self::uninit();
+}
+
+impl<T, const N: usize> ConstUninit for [T; N]
+where
+    T: ConstUninit,
+{
+    const UNINIT: Self = [<T as ConstUninit>::UNINIT; N];
+}
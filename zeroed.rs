@@ -0,0 +1,104 @@
+// Copyright 2021 Robin Freyler
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Implements a compilation time all-zero value for the implemented type.
+///
+/// # Note
+///
+/// The `Backend` names the crate that guarantees that the all-zero byte
+/// pattern is a valid value of the type:
+///
+/// - `ViaBytemuck` for types implementing `bytemuck::Zeroable` with the
+///   `bytemuck` crate feature.
+/// - `ViaZerocopy` for types implementing `zerocopy::FromZeros` with the
+///   `zerocopy` crate feature.
+///
+/// Use `#[const_default(zeroed(bytemuck))]` or
+/// `#[const_default(zeroed(zerocopy))]` on structs deriving `ConstDefault` to
+/// default them via this trait. This way the types of their fields do not
+/// need to implement `ConstDefault` themselves.
+pub trait ConstZeroed<Backend> {
+    /// The constant all-zero value.
+    const ZEROED: Self;
+}
+
+/// The [`ConstZeroed`] backend for types implementing `bytemuck::Zeroable`.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::{ConstDefault, ConstZeroed, ViaBytemuck};
+/// #[derive(Copy, Clone, bytemuck::Zeroable)]
+/// # #[derive(Debug, PartialEq)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: [u8; 4],
+///     length: u32,
+/// }
+///
+/// impl ConstDefault for Header {
+///     const DEFAULT: Self = <Self as ConstZeroed<ViaBytemuck>>::ZEROED;
+/// }
+///
+/// assert_eq!(
+///     <Header as ConstDefault>::DEFAULT,
+///     Header { magic: [0; 4], length: 0 },
+/// );
+/// ```
+#[cfg(feature = "bytemuck")]
+pub enum ViaBytemuck {}
+
+/// The [`ConstZeroed`] backend for types implementing `zerocopy::FromZeros`.
+///
+/// # Example
+///
+/// ```
+/// # use const_default::{ConstDefault, ConstZeroed, ViaZerocopy};
+/// #[derive(zerocopy::FromZeros)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Slot {
+///     occupied: bool,
+///     next: Option<core::num::NonZeroU32>,
+/// }
+///
+/// impl ConstDefault for Slot {
+///     const DEFAULT: Self = <Self as ConstZeroed<ViaZerocopy>>::ZEROED;
+/// }
+///
+/// assert_eq!(
+///     <Slot as ConstDefault>::DEFAULT,
+///     Slot { occupied: false, next: None },
+/// );
+/// ```
+#[cfg(feature = "zerocopy")]
+pub enum ViaZerocopy {}
+
+#[cfg(feature = "bytemuck")]
+impl<T> ConstZeroed<ViaBytemuck> for T
+where
+    T: bytemuck::Zeroable,
+{
+    const ZEROED: Self = bytemuck::zeroed();
+}
+
+#[cfg(feature = "zerocopy")]
+impl<T> ConstZeroed<ViaZerocopy> for T
+where
+    T: zerocopy::FromZeros,
+{
+    // SAFETY: `FromZeros` guarantees that the all-zero byte pattern is a
+    //         valid instance of `T`. `zerocopy` provides no `const`
+    //         constructor for it.
+    const ZEROED: Self = unsafe { core::mem::zeroed() };
+}